@@ -0,0 +1,72 @@
+// Compare point-by-point evaluation with batch evaluation over the same parameters, once with a
+// fresh output per batch and once writing into the buffers of a reused evaluator
+// Run with `cargo bench`, the numbers are nanoseconds per evaluated point
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use nurbs_wasm::{ControlPoint, CurveEvaluator, NurbsCurve};
+
+const POINTS: usize = 10_000;
+const ROUNDS: usize = 200;
+
+fn curve(degree: usize, num_control_points: usize) -> NurbsCurve {
+    let mut curve = NurbsCurve::new(degree);
+    for i in 0..num_control_points {
+        let x = i as f64;
+        let weight = 1.0 + 0.25 * (i % 4) as f64;
+        curve.add_control_point(ControlPoint::new(x, (x * 0.7).sin() * 10.0, weight));
+    }
+    curve
+}
+
+// Best time of several rounds, in nanoseconds per point
+fn time_per_point(mut run: impl FnMut()) -> f64 {
+    run();
+    let best = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    best.as_nanos() as f64 / POINTS as f64
+}
+
+fn main() {
+    let params: Vec<f64> = (0..POINTS)
+        .map(|i| i as f64 / (POINTS - 1) as f64)
+        .collect();
+
+    let mut evaluator = CurveEvaluator::new(3);
+    let mut out = vec![0.0; 2 * POINTS];
+
+    println!(
+        "degree  control points  evaluate (ns/pt)  evaluate_many (ns/pt)  evaluator (ns/pt)  speedup"
+    );
+    for degree in [1, 2, 3, 5] {
+        for num_control_points in [8, 50, 400] {
+            let curve = curve(degree, num_control_points);
+
+            let single = time_per_point(|| {
+                for &u in &params {
+                    black_box(curve.evaluate(black_box(u)).unwrap());
+                }
+            });
+            let batch = time_per_point(|| {
+                black_box(curve.evaluate_many(black_box(&params)).unwrap());
+            });
+            let reused = time_per_point(|| {
+                evaluator
+                    .evaluate_many_into(&curve, black_box(&params), &mut out)
+                    .unwrap();
+                black_box(&out);
+            });
+
+            println!(
+                "{degree:>6}  {num_control_points:>14}  {single:>16.1}  {batch:>21.1}  {reused:>17.1}  {:>6.2}x",
+                single / reused
+            );
+        }
+    }
+}
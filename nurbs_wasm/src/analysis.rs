@@ -0,0 +1,302 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{NurbsCurve, NurbsError};
+
+// Speeds below this fraction of the control polygon size per unit of parameter are treated as
+// cusps where the tangent is undefined
+const RELATIVE_MIN_SPEED: f64 = 1e-10;
+
+// Differential geometry sampled along a curve, laid out as flat arrays for drawing
+#[wasm_bindgen]
+pub struct CurvatureSamples {
+    parameters: Vec<f64>,
+    points: Vec<f64>,
+    normals: Vec<f64>,
+    curvatures: Vec<f64>,
+    degenerate: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl CurvatureSamples {
+    // Parameter values u of each sample
+    #[wasm_bindgen(getter)]
+    pub fn parameters(&self) -> Box<[f64]> {
+        self.parameters.clone().into_boxed_slice()
+    }
+
+    // Curve points as [x1, y1, x2, y2, ...]
+    #[wasm_bindgen(getter)]
+    pub fn points(&self) -> Box<[f64]> {
+        self.points.clone().into_boxed_slice()
+    }
+
+    // Unit normals as [nx1, ny1, nx2, ny2, ...], zero at degenerate samples
+    #[wasm_bindgen(getter)]
+    pub fn normals(&self) -> Box<[f64]> {
+        self.normals.clone().into_boxed_slice()
+    }
+
+    // Signed curvature of each sample, zero at degenerate samples
+    #[wasm_bindgen(getter)]
+    pub fn curvatures(&self) -> Box<[f64]> {
+        self.curvatures.clone().into_boxed_slice()
+    }
+
+    // Indices of samples where the curve has zero speed and no defined tangent
+    #[wasm_bindgen(getter)]
+    pub fn degenerate(&self) -> Box<[u32]> {
+        self.degenerate.clone().into_boxed_slice()
+    }
+
+    // Number of samples
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+}
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Unit tangent [tx, ty] at parameter u
+    // Fails where the curve cannot be evaluated or has zero speed (a cusp)
+    pub fn tangent_at(&self, u: f64) -> Result<Box<[f64]>, NurbsError> {
+        let (tangent, _) = self.frame_at(u)?;
+        Ok(Box::new(tangent))
+    }
+
+    // Unit normal [nx, ny] at parameter u, the tangent rotated a quarter turn counter-clockwise
+    // Fails where the curve cannot be evaluated or has zero speed (a cusp)
+    pub fn normal_at(&self, u: f64) -> Result<Box<[f64]>, NurbsError> {
+        let (tangent, _) = self.frame_at(u)?;
+        Ok(Box::new([-tangent[1], tangent[0]]))
+    }
+
+    // Signed curvature at parameter u, positive when the curve turns towards the normal
+    // Fails where the curve cannot be evaluated or has zero speed (a cusp)
+    pub fn curvature_at(&self, u: f64) -> Result<f64, NurbsError> {
+        let (_, curvature) = self.frame_at(u)?;
+        Ok(curvature)
+    }
+
+    // Radius of curvature at parameter u, infinite where the curve is locally straight
+    // Fails where the curve cannot be evaluated or has zero speed (a cusp)
+    pub fn radius_of_curvature_at(&self, u: f64) -> Result<f64, NurbsError> {
+        let curvature = self.curvature_at(u)?;
+        if curvature == 0.0 {
+            Ok(f64::INFINITY)
+        } else {
+            Ok(1.0 / curvature.abs())
+        }
+    }
+
+    // Sample points, unit normals and curvature at evenly spaced parameters for a curvature comb
+    // Cusps are listed as degenerate samples, other evaluation failures fail the whole call
+    pub fn sample_curvature(&self, num_samples: usize) -> Result<CurvatureSamples, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        let mut samples = CurvatureSamples {
+            parameters: Vec::new(),
+            points: Vec::new(),
+            normals: Vec::new(),
+            curvatures: Vec::new(),
+            degenerate: Vec::new(),
+        };
+
+        // Ensure we have at least 2 samples so both ends are covered
+        let actual_num_samples = num_samples.max(2);
+        let min_speed = self.min_speed();
+        let step = (end - start) / (actual_num_samples as f64 - 1.0);
+
+        samples.parameters.reserve(actual_num_samples);
+        samples.points.reserve(actual_num_samples * 2);
+        samples.normals.reserve(actual_num_samples * 2);
+        samples.curvatures.reserve(actual_num_samples);
+
+        for i in 0..actual_num_samples {
+            let u = if i == actual_num_samples - 1 {
+                end
+            } else {
+                start + i as f64 * step
+            };
+
+            let ders = self.rational_derivatives(u, 2)?;
+
+            samples.parameters.push(u);
+            samples.points.push(ders[0][0]);
+            samples.points.push(ders[0][1]);
+
+            match frame_from_derivatives(&ders, u, min_speed) {
+                Ok((tangent, curvature)) => {
+                    samples.normals.push(-tangent[1]);
+                    samples.normals.push(tangent[0]);
+                    samples.curvatures.push(curvature);
+                }
+                Err(_) => {
+                    samples.normals.push(0.0);
+                    samples.normals.push(0.0);
+                    samples.curvatures.push(0.0);
+                    samples
+                        .degenerate
+                        .push((samples.parameters.len() - 1) as u32);
+                }
+            }
+        }
+
+        Ok(samples)
+    }
+}
+
+impl NurbsCurve {
+    // Unit tangent and signed curvature at parameter u
+    fn frame_at(&self, u: f64) -> Result<([f64; 2], f64), NurbsError> {
+        let ders = self.rational_derivatives(u, 2)?;
+        frame_from_derivatives(&ders, u, self.min_speed())
+    }
+
+    // Speed below which the tangent is undefined, scaled by the extent of the control points and
+    // the length of the domain so the test does not depend on units or the knot range
+    fn min_speed(&self) -> f64 {
+        let (min, max) = self.control_points.iter().fold(
+            ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]),
+            |(min, max), cp| {
+                (
+                    [min[0].min(cp.x), min[1].min(cp.y)],
+                    [max[0].max(cp.x), max[1].max(cp.y)],
+                )
+            },
+        );
+        let extent = (max[0] - min[0]).hypot(max[1] - min[1]);
+        match self.parameter_range() {
+            Some((start, end)) if end > start && extent.is_finite() => {
+                RELATIVE_MIN_SPEED * extent / (end - start)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+// Build the unit tangent and signed curvature from the first two derivatives at u
+fn frame_from_derivatives(
+    ders: &[[f64; 2]],
+    u: f64,
+    min_speed: f64,
+) -> Result<([f64; 2], f64), NurbsError> {
+    let [dx, dy] = ders[1];
+    let [ddx, ddy] = ders[2];
+
+    let speed = (dx * dx + dy * dy).sqrt();
+    if !speed.is_finite() || speed <= min_speed {
+        return Err(NurbsError::UndefinedTangent { u });
+    }
+
+    let tangent = [dx / speed, dy / speed];
+    let curvature = (dx * ddy - dy * ddx) / (speed * speed * speed);
+
+    Ok((tangent, curvature))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve};
+
+    // Quarter circle of radius 2 as a rational quadratic
+    fn quarter_circle() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(2.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 2.0, std::f64::consts::FRAC_1_SQRT_2));
+        curve.add_control_point(ControlPoint::new(0.0, 2.0, 1.0));
+        curve
+    }
+
+    #[test]
+    fn test_curvature_of_circle() {
+        let curve = quarter_circle();
+
+        for i in 0..=10 {
+            let u = i as f64 / 10.0;
+            let curvature = curve.curvature_at(u).unwrap();
+            assert!((curvature - 0.5).abs() < 1e-10);
+            assert!((curve.radius_of_curvature_at(u).unwrap() - 2.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_tangent_and_normal_of_circle() {
+        let curve = quarter_circle();
+
+        // At the start the curve heads straight up and the normal points at the center
+        let tangent = curve.tangent_at(0.0).unwrap();
+        assert!(tangent[0].abs() < 1e-12);
+        assert!((tangent[1] - 1.0).abs() < 1e-12);
+
+        let normal = curve.normal_at(0.0).unwrap();
+        assert!((normal[0] + 1.0).abs() < 1e-12);
+        assert!(normal[1].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_straight_line_has_infinite_radius() {
+        let mut curve = NurbsCurve::new(1);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(3.0, 4.0, 1.0));
+
+        assert_eq!(curve.curvature_at(0.5), Ok(0.0));
+        assert_eq!(curve.radius_of_curvature_at(0.5), Ok(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cusp_is_reported() {
+        // Coincident control points stall the polyline over its first span
+        let mut curve = NurbsCurve::new(1);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(4.0, 0.0, 1.0));
+
+        assert!(curve.tangent_at(0.25).is_err());
+        assert!(curve.curvature_at(0.25).is_err());
+        assert!(curve.tangent_at(0.75).is_ok());
+
+        let samples = curve.sample_curvature(5).unwrap();
+        assert_eq!(samples.len(), 5);
+        assert_eq!(&samples.degenerate()[..], &[0, 1]);
+        assert!(samples.curvatures().iter().all(|k| k.is_finite()));
+    }
+
+    #[test]
+    fn test_cusp_threshold_follows_curve_size() {
+        // A tiny circle still has a tangent, its speed is small only because the curve is
+        let mut tiny = NurbsCurve::new(2);
+        for (x, y, w) in [
+            (1.0, 0.0, 1.0),
+            (1.0, 1.0, std::f64::consts::FRAC_1_SQRT_2),
+            (0.0, 1.0, 1.0),
+        ] {
+            tiny.add_control_point(ControlPoint::new(x * 1e-12, y * 1e-12, w));
+        }
+        let curvature = tiny.curvature_at(0.5).unwrap();
+        assert!((curvature * 1e-12 - 1.0).abs() < 1e-6);
+
+        // A huge curve on a long domain keeps its cusp
+        let mut cusp = NurbsCurve::new(1);
+        for x in [0.0, 0.0, 4e9] {
+            cusp.add_control_point(ControlPoint::new(x, 1e9, 1.0));
+        }
+        cusp.reparameterize(0.0, 1e6).unwrap();
+        assert!(cusp.tangent_at(0.25e6).is_err());
+        assert!(cusp.tangent_at(0.75e6).is_ok());
+    }
+
+    #[test]
+    fn test_sample_curvature_layout() {
+        let curve = quarter_circle();
+        let samples = curve.sample_curvature(8).unwrap();
+
+        assert_eq!(samples.parameters().len(), 8);
+        assert_eq!(samples.points().len(), 16);
+        assert_eq!(samples.normals().len(), 16);
+        assert_eq!(samples.curvatures().len(), 8);
+        assert!(samples.degenerate().is_empty());
+    }
+}
@@ -0,0 +1,435 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+// 5-point Gauss-Legendre abscissae and weights on [-1, 1]
+const GAUSS_NODES: [f64; 5] = [
+    -0.906_179_845_938_664,
+    -0.538_469_310_105_683_1,
+    0.0,
+    0.538_469_310_105_683_1,
+    0.906_179_845_938_664,
+];
+const GAUSS_WEIGHTS: [f64; 5] = [
+    0.236_926_885_056_189_1,
+    0.478_628_670_499_366_5,
+    0.568_888_888_888_888_9,
+    0.478_628_670_499_366_5,
+    0.236_926_885_056_189_1,
+];
+
+// Relative tolerance for the adaptive quadrature and the length inversion
+const LENGTH_TOLERANCE: f64 = 1e-10;
+
+// Maximum recursion depth of the adaptive quadrature
+const MAX_DEPTH: usize = 20;
+
+// Maximum number of Newton/bisection steps when inverting the arc length
+const MAX_INVERSION_STEPS: usize = 60;
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Length of the curve between parameters u0 and u1
+    // The result is negative when u1 < u0
+    pub fn arc_length(&self, u0: f64, u1: f64) -> Result<f64, NurbsError> {
+        let table = ArcLengthTable::new(self)?;
+        for u in [u0, u1] {
+            if u.is_nan() {
+                let (start, end) = self.checked_range()?;
+                return Err(NurbsError::ParameterOutOfDomain { u, start, end });
+            }
+        }
+        table.length_between(self, u0, u1)
+    }
+
+    // Total length of the curve
+    pub fn length(&self) -> Result<f64, NurbsError> {
+        let table = ArcLengthTable::new(self)?;
+        Ok(table.total())
+    }
+
+    // Parameter u at which the arc length measured from the start of the curve equals s
+    // s is clamped to [0, length()]
+    pub fn param_at_length(&self, s: f64) -> Result<f64, NurbsError> {
+        let table = ArcLengthTable::new(self)?;
+        table.param_at(self, s)
+    }
+
+    // Generate points evenly spaced along the curve by arc length
+    pub fn generate_points_equidistant(
+        &self,
+        num_points: usize,
+    ) -> Result<Vec<ControlPoint>, NurbsError> {
+        let table = ArcLengthTable::new(self)?;
+
+        // Ensure we have at least 2 points so both ends are covered
+        let actual_num_points = num_points.max(2);
+        let total = table.total();
+        let step = total / (actual_num_points as f64 - 1.0);
+
+        // Each search starts from the point before it, so only the gap between them is integrated
+        let mut points = Vec::with_capacity(actual_num_points);
+        let mut previous = None;
+        for i in 0..actual_num_points {
+            let s = if i == actual_num_points - 1 {
+                total
+            } else {
+                i as f64 * step
+            };
+            let (u, length) = table.locate(self, s, previous)?;
+            previous = Some((u, length));
+            points.push(self.evaluate(u)?);
+        }
+
+        Ok(points)
+    }
+}
+
+impl NurbsCurve {
+    // Speed |C'(u)| of the curve at parameter u
+    // Fails where the curve cannot be evaluated, such as a region of zero weight
+    fn speed(&self, u: f64) -> Result<f64, NurbsError> {
+        let ders = self.rational_derivatives(u, 1)?;
+        Ok((ders[1][0] * ders[1][0] + ders[1][1] * ders[1][1]).sqrt())
+    }
+
+    // Integrate the speed over [a, b] with a single Gauss-Legendre rule
+    fn gauss_length(&self, a: f64, b: f64) -> Result<f64, NurbsError> {
+        let half = 0.5 * (b - a);
+        let mid = 0.5 * (a + b);
+
+        let sum = GAUSS_NODES
+            .iter()
+            .zip(GAUSS_WEIGHTS.iter())
+            .map(|(x, w)| Ok(w * self.speed(mid + half * x)?))
+            .sum::<Result<f64, NurbsError>>()?;
+        Ok(sum * half)
+    }
+
+    // Integrate the speed over [a, b], bisecting until both halves agree with the whole
+    fn adaptive_length(&self, a: f64, b: f64, whole: f64, depth: usize) -> Result<f64, NurbsError> {
+        let mid = 0.5 * (a + b);
+        let left = self.gauss_length(a, mid)?;
+        let right = self.gauss_length(mid, b)?;
+        let refined = left + right;
+
+        if depth >= MAX_DEPTH
+            || (refined - whole).abs() <= LENGTH_TOLERANCE * refined.abs().max(1.0)
+        {
+            return Ok(refined);
+        }
+
+        Ok(self.adaptive_length(a, mid, left, depth + 1)?
+            + self.adaptive_length(mid, b, right, depth + 1)?)
+    }
+
+    // Length of the curve over [a, b] where both ends lie inside a single knot span
+    fn span_length(&self, a: f64, b: f64) -> Result<f64, NurbsError> {
+        if b <= a {
+            return Ok(0.0);
+        }
+        self.adaptive_length(a, b, self.gauss_length(a, b)?, 0)
+    }
+
+    // As span_length, negative when b < a
+    fn signed_span_length(&self, a: f64, b: f64) -> Result<f64, NurbsError> {
+        if b < a {
+            Ok(-self.span_length(b, a)?)
+        } else {
+            self.span_length(a, b)
+        }
+    }
+}
+
+// Cumulative arc length at each distinct knot of the curve domain
+// The speed is smooth inside a knot span, so quadrature never crosses a knot
+struct ArcLengthTable {
+    breakpoints: Vec<f64>,
+    cumulative: Vec<f64>,
+}
+
+impl ArcLengthTable {
+    fn new(curve: &NurbsCurve) -> Result<ArcLengthTable, NurbsError> {
+        // The curve must be evaluable for the quadrature to make sense
+        let (start, _) = curve.checked_range()?;
+        curve.rational_derivatives(start, 1)?;
+
+        let spans = curve.knot_spans();
+        let mut breakpoints = vec![curve.knots[curve.effective_degree()]];
+        breakpoints.extend(spans.iter().map(|&(_, b)| b));
+
+        let mut cumulative = Vec::with_capacity(breakpoints.len());
+        cumulative.push(0.0);
+        for pair in breakpoints.windows(2) {
+            let length = cumulative.last().unwrap() + curve.span_length(pair[0], pair[1])?;
+            cumulative.push(length);
+        }
+
+        Ok(ArcLengthTable {
+            breakpoints,
+            cumulative,
+        })
+    }
+
+    fn total(&self) -> f64 {
+        *self.cumulative.last().unwrap()
+    }
+
+    // Index of the piece [breakpoints[i], breakpoints[i + 1]] containing u
+    fn piece_containing(&self, u: f64) -> usize {
+        let last_piece = self.breakpoints.len().saturating_sub(2);
+        let upper = self.breakpoints.partition_point(|&b| b <= u);
+        upper.saturating_sub(1).min(last_piece)
+    }
+
+    // Arc length from the start of the curve to parameter u
+    fn length_to(&self, curve: &NurbsCurve, u: f64) -> Result<f64, NurbsError> {
+        let start = self.breakpoints[0];
+        let end = *self.breakpoints.last().unwrap();
+        let u = u.clamp(start, end);
+
+        if self.breakpoints.len() < 2 {
+            return Ok(0.0);
+        }
+
+        let piece = self.piece_containing(u);
+        Ok(self.cumulative[piece] + curve.span_length(self.breakpoints[piece], u)?)
+    }
+
+    fn length_between(&self, curve: &NurbsCurve, u0: f64, u1: f64) -> Result<f64, NurbsError> {
+        Ok(self.length_to(curve, u1)? - self.length_to(curve, u0)?)
+    }
+
+    // Parameter at which the arc length from the start of the curve equals s
+    fn param_at(&self, curve: &NurbsCurve, s: f64) -> Result<f64, NurbsError> {
+        Ok(self.locate(curve, s, None)?.0)
+    }
+
+    // Invert the arc length with Newton steps safeguarded by bisection, returning the parameter
+    // and the arc length measured up to it
+    // Every step integrates only from the parameter measured last, not from the start of the
+    // piece. A measured (u, length) pair in the same piece before s, such as the result for a
+    // smaller s, starts the search closer
+    fn locate(
+        &self,
+        curve: &NurbsCurve,
+        s: f64,
+        known: Option<(f64, f64)>,
+    ) -> Result<(f64, f64), NurbsError> {
+        if !s.is_finite() {
+            return Err(NurbsError::InvalidArgument(
+                "the arc length must be a finite number",
+            ));
+        }
+        if self.breakpoints.len() < 2 {
+            return Ok((self.breakpoints[0], 0.0));
+        }
+
+        let s = s.clamp(0.0, self.total());
+        let piece = self.cumulative[1..]
+            .partition_point(|&c| c < s)
+            .min(self.breakpoints.len() - 2);
+
+        let mut low = self.breakpoints[piece];
+        let mut high = self.breakpoints[piece + 1];
+        let piece_end = self.cumulative[piece + 1];
+
+        if piece_end <= self.cumulative[piece] {
+            return Ok((low, self.cumulative[piece]));
+        }
+
+        let (mut known_u, mut known_length) = match known {
+            Some((u, length)) if u >= low && u < high && length <= s && length < piece_end => {
+                (u, length)
+            }
+            _ => (low, self.cumulative[piece]),
+        };
+        low = known_u;
+
+        let tolerance = LENGTH_TOLERANCE * self.total().max(1.0);
+        let mut u = known_u + (high - known_u) * (s - known_length) / (piece_end - known_length);
+
+        for _ in 0..MAX_INVERSION_STEPS {
+            known_length += curve.signed_span_length(known_u, u)?;
+            known_u = u;
+            let error = known_length - s;
+            if error.abs() <= tolerance {
+                break;
+            }
+
+            if error > 0.0 {
+                high = u;
+            } else {
+                low = u;
+            }
+
+            // Take a Newton step, falling back to bisection when it leaves the bracket
+            let speed = curve.speed(u)?;
+            let newton = u - error / speed;
+            u = if speed > 0.0 && newton > low && newton < high {
+                newton
+            } else {
+                0.5 * (low + high)
+            };
+        }
+
+        Ok((known_u, known_length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+    fn line(x1: f64, y1: f64) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(1);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(x1, y1, 1.0));
+        curve
+    }
+
+    // Quarter circle of radius 1 as a rational quadratic
+    fn quarter_circle() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(1.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(1.0, 1.0, std::f64::consts::FRAC_1_SQRT_2));
+        curve.add_control_point(ControlPoint::new(0.0, 1.0, 1.0));
+        curve
+    }
+
+    #[test]
+    fn test_line_length() {
+        let curve = line(3.0, 4.0);
+        assert!((curve.length().unwrap() - 5.0).abs() < 1e-12);
+        assert!((curve.arc_length(0.2, 0.6).unwrap() - 2.0).abs() < 1e-12);
+        assert!((curve.arc_length(0.6, 0.2).unwrap() + 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quarter_circle_length() {
+        let curve = quarter_circle();
+        let expected = std::f64::consts::FRAC_PI_2;
+        assert!((curve.length().unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_length_across_knot_spans() {
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(1.0, 3.0, 2.0));
+        curve.add_control_point(ControlPoint::new(4.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(6.0, 2.0, 1.0));
+        curve.add_control_point(ControlPoint::new(8.0, 0.0, 1.0));
+
+        // A fine polyline converges to the same length from below
+        let points = curve.generate_points(20001).unwrap();
+        let polyline: f64 = points
+            .windows(2)
+            .map(|p| ((p[1].x() - p[0].x()).powi(2) + (p[1].y() - p[0].y()).powi(2)).sqrt())
+            .sum();
+        let length = curve.length().unwrap();
+        assert!(length >= polyline);
+        assert!((length - polyline).abs() < 1e-6);
+
+        // Lengths are additive across arbitrary split points
+        let a = curve.arc_length(0.0, 0.37).unwrap();
+        let b = curve.arc_length(0.37, 1.0).unwrap();
+        assert!((a + b - length).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_param_at_length_inverts_arc_length() {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 5.0, 3.0));
+        curve.add_control_point(ControlPoint::new(5.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(8.0, 4.0, 1.5));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+
+        let total = curve.length().unwrap();
+        for i in 0..=10 {
+            let s = total * i as f64 / 10.0;
+            let u = curve.param_at_length(s).unwrap();
+            assert!((curve.arc_length(0.0, u).unwrap() - s).abs() < 1e-8);
+        }
+
+        assert_eq!(curve.param_at_length(-1.0), Ok(0.0));
+        assert!((curve.param_at_length(total + 1.0).unwrap() - 1.0).abs() < 1e-12);
+        assert!(matches!(
+            curve.param_at_length(f64::NAN),
+            Err(NurbsError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_zero_weight_region_is_reported() {
+        // The middle third of the parameter range has no weight at all
+        let mut curve = NurbsCurve::new(1);
+        for (x, w) in [(0.0, 1.0), (1.0, 0.0), (2.0, 0.0), (3.0, 1.0)] {
+            curve.add_control_point(ControlPoint::new(x, 0.0, w));
+        }
+
+        assert!(matches!(curve.length(), Err(NurbsError::ZeroWeight { .. })));
+        assert!(matches!(
+            curve.arc_length(0.0, 0.2),
+            Err(NurbsError::ZeroWeight { .. })
+        ));
+        assert!(matches!(
+            curve.param_at_length(1.0),
+            Err(NurbsError::ZeroWeight { .. })
+        ));
+    }
+
+    #[test]
+    fn test_generate_points_equidistant() {
+        // Heavy middle weight makes uniform parameter sampling bunch up
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(5.0, 5.0, 10.0));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+
+        let points = curve.generate_points_equidistant(11).unwrap();
+        assert_eq!(points.len(), 11);
+        assert!((points[0].x() - 0.0).abs() < 1e-12);
+        assert!((points[10].x() - 10.0).abs() < 1e-12);
+
+        // Chords between consecutive points are nearly equal, unlike uniform parameter sampling
+        let chord_ratio = |points: &[ControlPoint]| {
+            let chords: Vec<f64> = points
+                .windows(2)
+                .map(|p| ((p[1].x() - p[0].x()).powi(2) + (p[1].y() - p[0].y()).powi(2)).sqrt())
+                .collect();
+            let max = chords.iter().cloned().fold(f64::MIN, f64::max);
+            let min = chords.iter().cloned().fold(f64::MAX, f64::min);
+            max / min
+        };
+        assert!(chord_ratio(&points) < 1.05);
+        assert!(chord_ratio(&curve.generate_points(11).unwrap()) > 2.0);
+
+        // Searching on from the previous point finds the same points as searching afresh,
+        // also where consecutive points fall into different knot spans
+        let mut curve = NurbsCurve::new(3);
+        for (x, y, w) in [
+            (0.0, 0.0, 1.0),
+            (2.0, 5.0, 3.0),
+            (5.0, -1.0, 0.5),
+            (8.0, 4.0, 1.5),
+            (10.0, 0.0, 1.0),
+        ] {
+            curve.add_control_point(ControlPoint::new(x, y, w));
+        }
+        let total = curve.length().unwrap();
+        let points = curve.generate_points_equidistant(25).unwrap();
+        for (i, point) in points.iter().enumerate() {
+            let u = curve.param_at_length(total * i as f64 / 24.0).unwrap();
+            let expected = curve.evaluate(u).unwrap();
+            assert!((point.x() - expected.x()).abs() < 1e-8);
+            assert!((point.y() - expected.y()).abs() < 1e-8);
+        }
+
+        assert!(matches!(
+            NurbsCurve::new(3).generate_points_equidistant(10),
+            Err(NurbsError::EmptyCurve)
+        ));
+    }
+}
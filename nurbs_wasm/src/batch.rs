@@ -0,0 +1,142 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core::{self, BatchScratch};
+use crate::{CurveEvaluator, NurbsCurve, NurbsError};
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Evaluate the curve at many parameters at once, returning a flat [x0, y0, x1, y1, ...] array
+    // The points equal those of evaluate, computed two at a time when neighbouring parameters
+    // share a knot span, so sorted parameters are the fastest
+    pub fn evaluate_many(&self, params: &[f64]) -> Result<Box<[f64]>, NurbsError> {
+        let mut out = vec![0.0; params.len() * 2];
+        CurveEvaluator::default().evaluate_many_into(self, params, &mut out)?;
+        Ok(out.into_boxed_slice())
+    }
+}
+
+impl NurbsCurve {
+    // Evaluate parameters already inside the domain into `out`, two values per parameter,
+    // reusing the scratch space between calls
+    pub(crate) fn evaluate_batch(
+        &self,
+        params: &[f64],
+        out: &mut [f64],
+        scratch: &mut BatchScratch,
+    ) -> Result<(), NurbsError> {
+        core::evaluate_batch(
+            &self.knots,
+            self.effective_degree(),
+            &self.control_points,
+            params,
+            out,
+            scratch,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, ControlPoint3, NurbsCurve, NurbsCurve3, NurbsError};
+
+    fn weighted_curve(degree: usize) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(degree);
+        for i in 0..9 {
+            let x = i as f64;
+            let weight = 1.0 + 0.3 * (i % 3) as f64;
+            curve.add_control_point(ControlPoint::new(x, (x * 1.3).sin() * 4.0, weight));
+        }
+        curve
+    }
+
+    // Parameters in order, out of order, repeated, on knots and outside the domain
+    fn awkward_parameters() -> Vec<f64> {
+        let mut params: Vec<f64> = (0..=97).map(|i| i as f64 / 97.0).collect();
+        params.extend([0.5, 0.1, 0.9, 0.9, 1.0 / 6.0, 2.0 / 3.0, 0.0, 1.0]);
+        params
+    }
+
+    #[test]
+    fn test_batch_matches_single_evaluation_bit_for_bit() {
+        for degree in 1..=5 {
+            let curve = weighted_curve(degree);
+            let params = awkward_parameters();
+            let batch = curve.evaluate_many(&params).unwrap();
+
+            for (i, &u) in params.iter().enumerate() {
+                let point = curve.evaluate(u).unwrap();
+                assert_eq!(batch[2 * i].to_bits(), point.x().to_bits());
+                assert_eq!(batch[2 * i + 1].to_bits(), point.y().to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_on_periodic_and_lowered_curves() {
+        let mut periodic = weighted_curve(3);
+        periodic.set_periodic(true).unwrap();
+        let params = [-0.75, 0.25, 1.25, 2.0];
+        let batch = periodic.evaluate_many(&params).unwrap();
+        for (i, &u) in params.iter().enumerate() {
+            let point = periodic.evaluate(u).unwrap();
+            assert_eq!((batch[2 * i], batch[2 * i + 1]), (point.x(), point.y()));
+        }
+
+        let mut line = NurbsCurve::new(3);
+        line.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        line.add_control_point(ControlPoint::new(2.0, 4.0, 1.0));
+        assert_eq!(line.evaluate_many(&[0.5]).unwrap().to_vec(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_batch_errors() {
+        let curve = weighted_curve(3);
+        assert!(curve.evaluate_many(&[]).unwrap().is_empty());
+        assert!(matches!(
+            curve.evaluate_many(&[0.2, f64::NAN]),
+            Err(NurbsError::ParameterOutOfDomain { .. })
+        ));
+        assert_eq!(
+            curve.evaluate_many(&[0.2, 1.5]),
+            Err(NurbsError::ParameterOutOfDomain {
+                u: 1.5,
+                start: 0.0,
+                end: 1.0
+            })
+        );
+        assert_eq!(
+            NurbsCurve::new(2).evaluate_many(&[0.5]),
+            Err(NurbsError::EmptyCurve)
+        );
+
+        let mut zero = NurbsCurve::new(1);
+        zero.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        zero.add_control_point(ControlPoint::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            zero.evaluate_many(&[0.5, 1.0]),
+            Err(NurbsError::ZeroWeight { u: 1.0 })
+        );
+    }
+
+    #[test]
+    fn test_generated_points_follow_the_batch_path() {
+        let curve = weighted_curve(3);
+        let points = curve.generate_points(33).unwrap();
+        for (i, point) in points.iter().enumerate() {
+            let u = if i == 32 { 1.0 } else { i as f64 / 32.0 };
+            let expected = curve.evaluate(u).unwrap();
+            assert_eq!((point.x(), point.y()), (expected.x(), expected.y()));
+        }
+
+        let mut curve3 = NurbsCurve3::new(2);
+        for i in 0..5 {
+            let t = i as f64;
+            curve3.add_control_point(ControlPoint3::new(t, t * t, -t, 1.0 + t));
+        }
+        let flat = curve3.generate_points(17).unwrap();
+        for i in 0..17 {
+            let expected = curve3.evaluate(i as f64 / 16.0).unwrap();
+            assert_eq!(&flat[3 * i..3 * i + 3], &expected[..]);
+        }
+    }
+}
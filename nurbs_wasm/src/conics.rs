@@ -0,0 +1,331 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use wasm_bindgen::prelude::*;
+
+use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+// Cross products below this treat two directions as parallel
+const PARALLEL_TOLERANCE: f64 = 1e-12;
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Exact full circle of radius r around (cx, cy), starting on the positive x axis
+    pub fn circle(cx: f64, cy: f64, r: f64) -> Result<NurbsCurve, NurbsError> {
+        NurbsCurve::arc(cx, cy, r, 0.0, TAU)
+    }
+
+    // Exact circular arc of radius r around (cx, cy), counter-clockwise from start_angle to end_angle
+    // Angles are in radians, an end angle at or before the start wraps around once
+    pub fn arc(
+        cx: f64,
+        cy: f64,
+        r: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Result<NurbsCurve, NurbsError> {
+        NurbsCurve::elliptical_arc(cx, cy, r, r, 0.0, start_angle, end_angle)
+    }
+
+    // Exact full ellipse around (cx, cy) with semi-axes rx and ry, the first rotated by `rotation` radians
+    pub fn ellipse(
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+    ) -> Result<NurbsCurve, NurbsError> {
+        NurbsCurve::elliptical_arc(cx, cy, rx, ry, rotation, 0.0, TAU)
+    }
+
+    // Quadratic rational segment from (x0, y0) to (x2, y2) with the given end tangent directions,
+    // passing through (px, py). The kind of conic follows from the middle weight:
+    // below 1 an ellipse, 1 a parabola, above 1 a hyperbola.
+    // Fails for parallel tangents or a point outside the triangle of the end points and the
+    // tangent intersection
+    #[allow(clippy::too_many_arguments)]
+    pub fn conic_from_three_points_and_tangents(
+        x0: f64,
+        y0: f64,
+        tx0: f64,
+        ty0: f64,
+        x2: f64,
+        y2: f64,
+        tx2: f64,
+        ty2: f64,
+        px: f64,
+        py: f64,
+    ) -> Result<NurbsCurve, NurbsError> {
+        let (p0, p2, p) = ([x0, y0], [x2, y2], [px, py]);
+        let outside = NurbsError::InvalidArgument(
+            "the point must lie inside the triangle of the end points and the tangent intersection",
+        );
+
+        // The middle control point is where the end tangents meet
+        let p1 = intersect_lines(p0, [tx0, ty0], p2, [tx2, ty2]).ok_or(
+            NurbsError::InvalidArgument("the end tangents must not be parallel"),
+        )?;
+
+        // The line from P1 through P hits the chord P0 P2 at a fraction s,
+        // and a point projected there has parameter u with u / (1 - u) = sqrt(s / (1 - s))
+        let (t, s) = line_parameters(p1, sub(p, p1), p0, sub(p2, p0)).ok_or(outside.clone())?;
+        if t <= 1.0 || s <= 0.0 || s >= 1.0 {
+            return Err(outside);
+        }
+        let a = (s / (1.0 - s)).sqrt();
+        let u = a / (1.0 + a);
+
+        let to_p1 = sub(p1, p);
+        let numerator = (1.0 - u).powi(2) * dot(sub(p, p0), to_p1) + u * u * dot(sub(p, p2), to_p1);
+        let denominator = 2.0 * u * (1.0 - u) * dot(to_p1, to_p1);
+        let weight = numerator / denominator;
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err(outside);
+        }
+
+        Ok(NurbsCurve::from_parts(
+            vec![
+                ControlPoint::new(p0[0], p0[1], 1.0),
+                ControlPoint::new(p1[0], p1[1], weight),
+                ControlPoint::new(p2[0], p2[1], 1.0),
+            ],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            2,
+        ))
+    }
+}
+
+impl NurbsCurve {
+    // Exact elliptical arc built from quadratic segments of at most a quarter turn each
+    // (The NURBS Book, A7.1), mapped from the unit circle by scaling, rotation and translation
+    #[allow(clippy::too_many_arguments)]
+    pub fn elliptical_arc(
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Result<NurbsCurve, NurbsError> {
+        let finite = [cx, cy, rx, ry, rotation, start_angle, end_angle]
+            .iter()
+            .all(|v| v.is_finite());
+        if !finite {
+            return Err(NurbsError::InvalidArgument(
+                "all arguments must be finite numbers",
+            ));
+        }
+        if rx <= 0.0 || ry <= 0.0 {
+            return Err(NurbsError::InvalidArgument("the radii must be positive"));
+        }
+
+        let mut sweep = end_angle - start_angle;
+        if sweep <= 0.0 {
+            sweep += TAU * (-sweep / TAU).floor() + TAU;
+        }
+        if sweep <= 0.0 || sweep > TAU * (1.0 + 1e-12) {
+            return Err(NurbsError::InvalidArgument(
+                "the arc must sweep at most a full turn",
+            ));
+        }
+        let sweep = sweep.min(TAU);
+
+        let segments = ((sweep / FRAC_PI_2).ceil() as usize).clamp(1, 4);
+        let step = sweep / segments as f64;
+        let middle_weight = (step / 2.0).cos();
+
+        let (sin_rotation, cos_rotation) = rotation.sin_cos();
+        let place = |x: f64, y: f64, weight: f64| {
+            let (x, y) = (rx * x, ry * y);
+            ControlPoint::new(
+                cx + cos_rotation * x - sin_rotation * y,
+                cy + sin_rotation * x + cos_rotation * y,
+                weight,
+            )
+        };
+
+        // On the unit circle the middle point of a segment lies on the bisecting ray at 1 / cos(step / 2)
+        let mut control_points = Vec::with_capacity(2 * segments + 1);
+        control_points.push(place(start_angle.cos(), start_angle.sin(), 1.0));
+        for i in 0..segments {
+            let middle = start_angle + (i as f64 + 0.5) * step;
+            let end = start_angle + (i + 1) as f64 * step;
+            control_points.push(place(
+                middle.cos() / middle_weight,
+                middle.sin() / middle_weight,
+                middle_weight,
+            ));
+            control_points.push(place(end.cos(), end.sin(), 1.0));
+        }
+
+        // Interior knots are doubled where the segments meet
+        let mut knots = vec![0.0; 3];
+        for i in 1..segments {
+            let knot = i as f64 / segments as f64;
+            knots.extend_from_slice(&[knot, knot]);
+        }
+        knots.extend_from_slice(&[1.0; 3]);
+
+        Ok(NurbsCurve::from_parts(control_points, knots, 2))
+    }
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+// Parameters (t, s) where p + t * d meets q + s * e, None for parallel lines
+fn line_parameters(p: [f64; 2], d: [f64; 2], q: [f64; 2], e: [f64; 2]) -> Option<(f64, f64)> {
+    let cross = d[0] * e[1] - d[1] * e[0];
+    let scale = (dot(d, d) * dot(e, e)).sqrt();
+    if scale == 0.0 || cross.abs() <= PARALLEL_TOLERANCE * scale {
+        return None;
+    }
+
+    let w = sub(q, p);
+    let t = (w[0] * e[1] - w[1] * e[0]) / cross;
+    let s = (w[0] * d[1] - w[1] * d[0]) / cross;
+    Some((t, s))
+}
+
+// Intersection of the line through p with direction d and the line through q with direction e
+fn intersect_lines(p: [f64; 2], d: [f64; 2], q: [f64; 2], e: [f64; 2]) -> Option<[f64; 2]> {
+    let (t, _) = line_parameters(p, d, q, e)?;
+    Some([p[0] + t * d[0], p[1] + t * d[1]])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NurbsCurve;
+    use std::f64::consts::{PI, TAU};
+
+    // Largest deviation of sampled curve points from the implicit shape f(x, y) = 0
+    fn max_residual(curve: &NurbsCurve, f: impl Fn(f64, f64) -> f64) -> f64 {
+        (0..=1000)
+            .map(|i| {
+                let p = curve.evaluate(i as f64 / 1000.0).unwrap();
+                f(p.x(), p.y()).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_circle() {
+        let curve = NurbsCurve::circle(1.0, -2.0, 3.0).unwrap();
+        assert_eq!(curve.get_degree(), 2);
+        assert_eq!(curve.num_control_points(), 9);
+        assert_eq!(
+            curve.get_knots(),
+            vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0]
+        );
+
+        let residual = max_residual(&curve, |x, y| (x - 1.0).hypot(y + 2.0) - 3.0);
+        assert!(residual < 1e-12);
+
+        // The curve closes where it started, on the positive x axis
+        let start = curve.evaluate(0.0).unwrap();
+        let end = curve.evaluate(1.0).unwrap();
+        assert!((start.x() - 4.0).abs() < 1e-12 && (start.y() + 2.0).abs() < 1e-12);
+        assert!((end.x() - start.x()).abs() < 1e-12 && (end.y() - start.y()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_arcs() {
+        for &(start, end, segments) in &[
+            (0.3, 1.2, 1),
+            (-0.5, 2.0, 2),
+            (1.0, 1.0 + 1.4 * PI, 3),
+            (2.0, 1.0, 4),
+        ] {
+            let curve = NurbsCurve::arc(0.5, 0.5, 2.0, start, end).unwrap();
+            assert_eq!(curve.num_control_points(), 2 * segments + 1);
+
+            let residual = max_residual(&curve, |x, y| (x - 0.5).hypot(y - 0.5) - 2.0);
+            assert!(residual < 1e-12);
+
+            let first = curve.evaluate(0.0).unwrap();
+            let last = curve.evaluate(1.0).unwrap();
+            assert!((first.x() - (0.5 + 2.0 * start.cos())).abs() < 1e-12);
+            assert!((first.y() - (0.5 + 2.0 * start.sin())).abs() < 1e-12);
+            assert!((last.x() - (0.5 + 2.0 * end.cos())).abs() < 1e-12);
+            assert!((last.y() - (0.5 + 2.0 * end.sin())).abs() < 1e-12);
+
+            // The arc runs counter-clockwise
+            let tangent = curve.derivatives(0.0, 1).unwrap();
+            assert!(tangent[2] * -start.sin() + tangent[3] * start.cos() > 0.0);
+        }
+
+        assert!(NurbsCurve::arc(0.0, 0.0, 0.0, 0.0, 1.0).is_err());
+        assert!(NurbsCurve::arc(0.0, 0.0, 1.0, 0.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_ellipse() {
+        let rotation: f64 = 0.4;
+        let curve = NurbsCurve::ellipse(2.0, 1.0, 5.0, 2.0, rotation).unwrap();
+        assert_eq!(curve.num_control_points(), 9);
+
+        let (sin, cos) = rotation.sin_cos();
+        let residual = max_residual(&curve, |x, y| {
+            let (dx, dy) = (x - 2.0, y - 1.0);
+            let (a, b) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+            (a / 5.0).powi(2) + (b / 2.0).powi(2) - 1.0
+        });
+        assert!(residual < 1e-12);
+        assert!(NurbsCurve::ellipse(0.0, 0.0, 1.0, -1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_conic_through_circle_points() {
+        // A quarter of the unit circle from its end points, end tangents and the 45 degree point
+        let s = 0.5_f64.sqrt();
+        let curve = NurbsCurve::conic_from_three_points_and_tangents(
+            1.0, 0.0, 0.0, 1.0, 0.0, 1.0, -1.0, 0.0, s, s,
+        )
+        .unwrap();
+
+        let middle = curve.get_control_point(1).unwrap();
+        assert!((middle.weight() - s).abs() < 1e-12);
+        assert!(max_residual(&curve, |x, y| x.hypot(y) - 1.0) < 1e-12);
+    }
+
+    #[test]
+    fn test_conic_kinds() {
+        // Parabola y = x^2 between x = -1 and x = 1, tangents meet at (0, -1)
+        let parabola = NurbsCurve::conic_from_three_points_and_tangents(
+            -1.0, 1.0, 1.0, -2.0, 1.0, 1.0, 1.0, 2.0, 0.5, 0.25,
+        )
+        .unwrap();
+        assert!((parabola.get_control_point(1).unwrap().weight() - 1.0).abs() < 1e-12);
+        assert!(max_residual(&parabola, |x, y| y - x * x) < 1e-12);
+
+        // Hyperbola x * y = 1 between (0.5, 2) and (2, 0.5)
+        let hyperbola = NurbsCurve::conic_from_three_points_and_tangents(
+            0.5, 2.0, 1.0, -4.0, 2.0, 0.5, 4.0, -1.0, 1.25, 0.8,
+        )
+        .unwrap();
+        assert!(hyperbola.get_control_point(1).unwrap().weight() > 1.0);
+        assert!(max_residual(&hyperbola, |x, y| x * y - 1.0) < 1e-12);
+
+        // Parallel tangents and points outside the control triangle are rejected
+        assert!(NurbsCurve::conic_from_three_points_and_tangents(
+            0.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 1.0, 1.0, 1.0
+        )
+        .is_err());
+        assert!(NurbsCurve::conic_from_three_points_and_tangents(
+            1.0, 0.0, 0.0, 1.0, 0.0, 1.0, -1.0, 0.0, 3.0, 3.0
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_full_turn_arc_matches_circle() {
+        let arc = NurbsCurve::arc(0.0, 0.0, 1.0, 0.0, TAU).unwrap();
+        let circle = NurbsCurve::circle(0.0, 0.0, 1.0).unwrap();
+        assert_eq!(arc.get_knots(), circle.get_knots());
+    }
+}
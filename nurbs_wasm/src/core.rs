@@ -0,0 +1,700 @@
+use crate::error::{KnotError, NurbsError};
+use crate::simd::F64x2;
+
+// A control point with D coordinates and a weight
+pub trait WeightedPoint<const D: usize> {
+    // Euclidean coordinates of the point
+    fn coordinates(&self) -> [f64; D];
+
+    fn weight(&self) -> f64;
+}
+
+// Generate a uniform clamped knot vector on [0, 1] for the given number of control points
+// The degree is used as given, curves with fewer than degree + 1 points cannot be evaluated
+pub fn uniform_clamped_knots(num_points: usize, degree: usize) -> Vec<f64> {
+    // Safety check: ensure we have at least one control point
+    if num_points == 0 {
+        return vec![0.0, 1.0]; // Default knot vector
+    }
+
+    let n = num_points - 1;
+    let m = n + degree + 1;
+
+    let mut knots = Vec::with_capacity(m + 1);
+
+    // For a clamped knot vector
+    for i in 0..=m {
+        let knot = if i < degree {
+            0.0
+        } else if i > n {
+            1.0
+        } else {
+            (i - degree) as f64 / (n - degree + 1) as f64
+        };
+        knots.push(knot);
+    }
+
+    knots
+}
+
+// Find the knot span for a given parameter u
+pub fn find_span(knots: &[f64], degree: usize, num_points: usize, u: f64) -> Option<usize> {
+    // Check if we have enough control points and knots
+    if num_points == 0 || knots.len() < 2 {
+        return None;
+    }
+
+    let n = num_points - 1;
+
+    // Ensure we have enough knots for the degree
+    if n + 1 >= knots.len() || degree >= knots.len() {
+        return None;
+    }
+
+    // Clamp u to the curve domain [knots[degree], knots[n + 1]]
+    let u = u.max(knots[degree]).min(knots[n + 1]);
+
+    if u >= knots[n + 1] {
+        return Some(n);
+    }
+
+    if u <= knots[degree] {
+        return Some(degree);
+    }
+
+    let mut low = degree;
+    let mut high = n + 1;
+
+    // Safety check to prevent infinite loop
+    let max_iterations = 100;
+    let mut iterations = 0;
+
+    let mut mid = (low + high) / 2;
+
+    while (u < knots[mid] || u >= knots[mid + 1]) && iterations < max_iterations {
+        if u < knots[mid] {
+            high = mid;
+        } else {
+            low = mid;
+        }
+        mid = (low + high) / 2;
+        iterations += 1;
+    }
+
+    // If we hit max iterations, return a safe value
+    if iterations >= max_iterations {
+        return Some(degree);
+    }
+
+    Some(mid)
+}
+
+// First and last parameter of the domain of a curve with num_points control points
+pub fn parameter_range(knots: &[f64], degree: usize, num_points: usize) -> Option<(f64, f64)> {
+    if num_points == 0 {
+        return None;
+    }
+
+    let n = num_points - 1;
+    if n + 1 >= knots.len() || degree >= knots.len() {
+        return None;
+    }
+
+    Some((knots[degree], knots[n + 1]))
+}
+
+// Domain of a curve with num_points control points, or why the curve cannot be evaluated
+// Only the layout is checked here, set_knots validates the knot values themselves
+pub fn check_curve(
+    knots: &[f64],
+    degree: usize,
+    num_points: usize,
+) -> Result<(f64, f64), NurbsError> {
+    if num_points == 0 {
+        return Err(NurbsError::EmptyCurve);
+    }
+    if num_points < degree + 1 {
+        return Err(NurbsError::InvalidDegree { degree, num_points });
+    }
+
+    let expected = num_points + degree + 1;
+    if knots.len() != expected {
+        return Err(KnotError::WrongLength {
+            expected,
+            actual: knots.len(),
+        }
+        .into());
+    }
+
+    Ok((knots[degree], knots[num_points]))
+}
+
+// Check that u lies in the domain and find its span
+fn checked_span(
+    knots: &[f64],
+    degree: usize,
+    num_points: usize,
+    u: f64,
+) -> Result<usize, NurbsError> {
+    let (start, end) = check_curve(knots, degree, num_points)?;
+    check_parameter(u, start, end)?;
+
+    // Cannot fail once the layout is checked
+    find_span(knots, degree, num_points, u).ok_or(NurbsError::EmptyCurve)
+}
+
+// Parameters outside [start, end], and NaN, have no point on the curve
+pub(crate) fn check_parameter(u: f64, start: f64, end: f64) -> Result<f64, NurbsError> {
+    if (start..=end).contains(&u) {
+        Ok(u)
+    } else {
+        Err(NurbsError::ParameterOutOfDomain { u, start, end })
+    }
+}
+
+// Calculate the basis functions for a given parameter u and span
+// SIMD-optimized implementation
+pub fn basis_functions(knots: &[f64], degree: usize, span: usize, u: f64) -> Vec<f64> {
+    // Safety check: ensure we have enough knots
+    if span + degree >= knots.len() || span < degree {
+        return vec![0.0; degree + 1];
+    }
+
+    let mut basis = vec![0.0; degree + 1];
+    let mut left = vec![0.0; degree + 1];
+    let mut right = vec![0.0; degree + 1];
+
+    basis[0] = 1.0;
+
+    // SIMD-optimized Cox-de Boor recursion formula
+    for j in 1..=degree {
+        // Safety check: ensure indices are valid
+        if span + 1 < j || span + j >= knots.len() {
+            continue;
+        }
+
+        left[j] = u - knots[span + 1 - j];
+        right[j] = knots[span + j] - u;
+
+        let mut saved = 0.0;
+
+        // Process basis functions in pairs when possible for SIMD
+        let mut r = 0;
+        while r + 1 < j {
+            // Load pairs of values for SIMD processing
+            let right_vec = F64x2::new(right[r + 1], right[r + 2]);
+            let left_vec = F64x2::new(left[j - r], left[j - r - 1]);
+            let basis_vec = F64x2::new(basis[r], basis[r + 1]);
+
+            // Calculate divisors and quotients using SIMD
+            let divisor_vec = right_vec + left_vec;
+            let [divisor0, divisor1] = divisor_vec.to_array();
+            let [quotient0, quotient1] = (basis_vec / divisor_vec).to_array();
+
+            // Near-zero divisors contribute nothing
+            let temp0 = if divisor0.abs() < 1e-10 {
+                0.0
+            } else {
+                quotient0
+            };
+            let temp1 = if divisor1.abs() < 1e-10 {
+                0.0
+            } else {
+                quotient1
+            };
+
+            // Update basis values
+            basis[r] = saved + right[r + 1] * temp0;
+            basis[r + 1] = left[j - r] * temp0 + right[r + 2] * temp1;
+            saved = left[j - r - 1] * temp1;
+
+            r += 2;
+        }
+
+        // Handle remaining single element if j is odd
+        if r < j {
+            let divisor = right[r + 1] + left[j - r];
+            let temp = if divisor.abs() < 1e-10 {
+                0.0
+            } else {
+                basis[r] / divisor
+            };
+
+            basis[r] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+
+        basis[j] = saved;
+    }
+
+    basis
+}
+
+// Calculate the basis functions and their derivatives up to order n
+// Returns ders[k][j] = k-th derivative of N_{span-degree+j, degree} at u
+pub fn basis_function_derivatives(
+    knots: &[f64],
+    degree: usize,
+    span: usize,
+    u: f64,
+    n: usize,
+) -> Vec<Vec<f64>> {
+    let p = degree;
+    let mut ders = vec![vec![0.0; p + 1]; n + 1];
+
+    // Safety check: ensure we have enough knots
+    if span + p >= knots.len() || span < p {
+        return ders;
+    }
+
+    // ndu stores the basis functions (upper triangle) and knot differences (lower triangle)
+    let mut ndu = vec![vec![0.0; p + 1]; p + 1];
+    let mut left = vec![0.0; p + 1];
+    let mut right = vec![0.0; p + 1];
+
+    ndu[0][0] = 1.0;
+
+    for j in 1..=p {
+        left[j] = u - knots[span + 1 - j];
+        right[j] = knots[span + j] - u;
+
+        let mut saved = 0.0;
+        for r in 0..j {
+            ndu[j][r] = right[r + 1] + left[j - r];
+            let temp = if ndu[j][r].abs() < 1e-10 {
+                0.0
+            } else {
+                ndu[r][j - 1] / ndu[j][r]
+            };
+            ndu[r][j] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        ndu[j][j] = saved;
+    }
+
+    for j in 0..=p {
+        ders[0][j] = ndu[j][p];
+    }
+
+    // Divide by a knot difference, treating degenerate spans as zero contributions
+    let safe_div = |a: f64, b: f64| if b.abs() < 1e-10 { 0.0 } else { a / b };
+
+    // Compute the derivatives using two alternating rows of coefficients
+    let mut a = vec![vec![0.0; p + 1]; 2];
+    for r in 0..=p {
+        let mut s1 = 0;
+        let mut s2 = 1;
+        a[0][0] = 1.0;
+
+        for k in 1..=n.min(p) {
+            let mut d = 0.0;
+            let rk = r as isize - k as isize;
+            let pk = p - k;
+
+            if rk >= 0 {
+                let rk = rk as usize;
+                a[s2][0] = safe_div(a[s1][0], ndu[pk + 1][rk]);
+                d = a[s2][0] * ndu[rk][pk];
+            }
+
+            let j1 = if rk >= -1 { 1 } else { (-rk) as usize };
+            let j2 = if r <= pk + 1 { k - 1 } else { p - r };
+
+            for j in j1..=j2 {
+                let idx = (rk + j as isize) as usize;
+                a[s2][j] = safe_div(a[s1][j] - a[s1][j - 1], ndu[pk + 1][idx]);
+                d += a[s2][j] * ndu[idx][pk];
+            }
+
+            if r <= pk {
+                a[s2][k] = safe_div(-a[s1][k - 1], ndu[pk + 1][r]);
+                d += a[s2][k] * ndu[r][pk];
+            }
+
+            ders[k][r] = d;
+            std::mem::swap(&mut s1, &mut s2);
+        }
+    }
+
+    // Multiply through by the correct factors p!/(p-k)!
+    let mut factor = p as f64;
+    for (k, row) in ders.iter_mut().enumerate().skip(1).take(n.min(p)) {
+        for value in row.iter_mut() {
+            *value *= factor;
+        }
+        factor *= (p - k) as f64;
+    }
+
+    ders
+}
+
+// Evaluate a rational curve with D-dimensional control points at parameter u
+pub fn evaluate<const D: usize, P: WeightedPoint<D>>(
+    knots: &[f64],
+    degree: usize,
+    points: &[P],
+    u: f64,
+) -> Result<[f64; D], NurbsError> {
+    // Find the knot span for parameter u
+    let span = checked_span(knots, degree, points.len(), u)?;
+
+    // Calculate basis functions
+    let basis = basis_functions(knots, degree, span, u);
+
+    let mut numerator = [0.0; D];
+    let mut denominator = 0.0;
+
+    // SIMD-optimized evaluation using vectorized operations
+    // Process control points in batches of 2 for SIMD efficiency
+    let mut i = 0;
+    while i < degree {
+        let cp1 = &points[span - degree + i];
+        let cp2 = &points[span - degree + i + 1];
+
+        let weight_vec = F64x2::new(cp1.weight(), cp2.weight());
+        let basis_vec = F64x2::new(basis[i], basis[i + 1]);
+        let weighted_basis_vec = basis_vec * weight_vec;
+
+        let coordinates1 = cp1.coordinates();
+        let coordinates2 = cp2.coordinates();
+        for (d, value) in numerator.iter_mut().enumerate() {
+            let contrib = weighted_basis_vec * F64x2::new(coordinates1[d], coordinates2[d]);
+            *value += contrib.sum();
+        }
+        denominator += weighted_basis_vec.sum();
+
+        i += 2;
+    }
+
+    // Handle any remaining control point if degree is even
+    if i <= degree {
+        let cp = &points[span - degree + i];
+        let weighted_basis = basis[i] * cp.weight();
+        for (value, coordinate) in numerator.iter_mut().zip(cp.coordinates()) {
+            *value += weighted_basis * coordinate;
+        }
+        denominator += weighted_basis;
+    }
+
+    // Avoid division by zero
+    if denominator.abs() < 1e-10 {
+        return Err(NurbsError::ZeroWeight { u });
+    }
+
+    Ok(numerator.map(|value| value / denominator))
+}
+
+// Parameters of evenly spaced samples across [start, end], at least two
+// The last one lies exactly at the end of the domain
+pub fn uniform_parameters(start: f64, end: f64, num_points: usize) -> Vec<f64> {
+    let mut params = vec![0.0; num_points.max(2)];
+    fill_uniform_parameters(start, end, &mut params);
+    params
+}
+
+// Overwrite params with evenly spaced parameters across [start, end], as uniform_parameters
+pub fn fill_uniform_parameters(start: f64, end: f64, params: &mut [f64]) {
+    let count = params.len();
+    let step = (end - start) / (count as f64 - 1.0);
+    for (i, u) in params.iter_mut().enumerate() {
+        *u = if i == count - 1 {
+            end
+        } else {
+            start + i as f64 * step
+        };
+    }
+}
+
+// Working memory for batch evaluation, kept between batches so evaluating allocates nothing
+// per point. Each entry holds one value per SIMD lane
+#[derive(Clone, Debug, Default)]
+pub struct BatchScratch {
+    basis: Vec<F64x2>,
+    left: Vec<F64x2>,
+    right: Vec<F64x2>,
+}
+
+impl BatchScratch {
+    // Scratch space already sized for curves up to the given degree
+    pub fn with_degree(degree: usize) -> BatchScratch {
+        let mut scratch = BatchScratch::default();
+        scratch.prepare(degree);
+        scratch
+    }
+
+    // Reset the buffers to degree + 1 zeroed entries, they keep their capacity so only a degree
+    // higher than any before allocates
+    fn prepare(&mut self, degree: usize) {
+        for buffer in [&mut self.basis, &mut self.left, &mut self.right] {
+            buffer.clear();
+            buffer.resize(degree + 1, F64x2::splat(0.0));
+        }
+    }
+}
+
+// Evaluate a rational curve at many parameters, writing D coordinates per parameter to `out`
+// Neighbouring parameters in the same knot span share one basis computation, one parameter per
+// SIMD lane. Each lane repeats the arithmetic of `evaluate`, so the results match it bit for bit
+pub fn evaluate_batch<const D: usize, P: WeightedPoint<D>>(
+    knots: &[f64],
+    degree: usize,
+    points: &[P],
+    params: &[f64],
+    out: &mut [f64],
+    scratch: &mut BatchScratch,
+) -> Result<(), NurbsError> {
+    let (start, end) = check_curve(knots, degree, points.len())?;
+    if out.len() != params.len() * D {
+        return Err(NurbsError::InvalidArgument(
+            "the output needs one point per parameter",
+        ));
+    }
+    scratch.prepare(degree);
+
+    // Like `evaluate`, parameters outside the domain have no point
+    let checked = |u: f64| check_parameter(u, start, end);
+
+    let mut span = degree;
+    let mut i = 0;
+    while i < params.len() {
+        let u0 = checked(params[i])?;
+        span = nearby_span(knots, degree, points.len(), u0, span)?;
+
+        // Pair with the next parameter if it lies in the same span, otherwise both lanes take u0
+        // A NaN partner is reported when its own turn comes
+        let (mut u1, mut lanes) = (u0, 1);
+        if let Some(Ok(next)) = params.get(i + 1).map(|&u| checked(u)) {
+            if nearby_span(knots, degree, points.len(), next, span)? == span {
+                (u1, lanes) = (next, 2);
+            }
+        }
+
+        basis_lanes(knots, degree, span, F64x2::new(u0, u1), scratch);
+        let (numerator, denominator) =
+            combine_lanes::<D, P>(&points[span - degree..=span], scratch);
+
+        let denominators = denominator.to_array();
+        for (lane, u) in [u0, u1].into_iter().enumerate().take(lanes) {
+            // Avoid division by zero
+            if denominators[lane].abs() < 1e-10 {
+                return Err(NurbsError::ZeroWeight { u });
+            }
+        }
+        for (d, value) in numerator.iter().enumerate() {
+            let coordinates = (*value / denominator).to_array();
+            for (lane, coordinate) in coordinates.into_iter().enumerate().take(lanes) {
+                out[(i + lane) * D + d] = coordinate;
+            }
+        }
+
+        i += lanes;
+    }
+
+    Ok(())
+}
+
+// Span of a parameter inside the domain, trying the span of the previous parameter before searching
+fn nearby_span(
+    knots: &[f64],
+    degree: usize,
+    num_points: usize,
+    u: f64,
+    previous: usize,
+) -> Result<usize, NurbsError> {
+    // Inside the domain the span is the one interval [knots[s], knots[s + 1]) containing u
+    let inside = u > knots[degree] && u < knots[num_points];
+    if inside && knots[previous] <= u && u < knots[previous + 1] {
+        return Ok(previous);
+    }
+
+    // Cannot fail once the layout is checked
+    find_span(knots, degree, num_points, u).ok_or(NurbsError::EmptyCurve)
+}
+
+// Basis functions of one span for the parameters in the lanes of u, as in basis_functions
+fn basis_lanes(knots: &[f64], degree: usize, span: usize, u: F64x2, scratch: &mut BatchScratch) {
+    let BatchScratch { basis, left, right } = scratch;
+    basis[0] = F64x2::splat(1.0);
+
+    for j in 1..=degree {
+        left[j] = u - F64x2::splat(knots[span + 1 - j]);
+        right[j] = F64x2::splat(knots[span + j]) - u;
+
+        let mut saved = F64x2::splat(0.0);
+        for r in 0..j {
+            let divisor = right[r + 1] + left[j - r];
+
+            // Near-zero divisors contribute nothing
+            let quotient = basis[r] / divisor;
+            let [divisor0, divisor1] = divisor.to_array();
+            let temp = if divisor0.abs() < 1e-10 || divisor1.abs() < 1e-10 {
+                let [quotient0, quotient1] = quotient.to_array();
+                F64x2::new(
+                    if divisor0.abs() < 1e-10 {
+                        0.0
+                    } else {
+                        quotient0
+                    },
+                    if divisor1.abs() < 1e-10 {
+                        0.0
+                    } else {
+                        quotient1
+                    },
+                )
+            } else {
+                quotient
+            };
+
+            basis[r] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        basis[j] = saved;
+    }
+}
+
+// Weighted sums of the control points of one span, in the pairwise order of `evaluate`
+fn combine_lanes<const D: usize, P: WeightedPoint<D>>(
+    points: &[P],
+    scratch: &BatchScratch,
+) -> ([F64x2; D], F64x2) {
+    let basis = &scratch.basis;
+    let mut numerator = [F64x2::splat(0.0); D];
+    let mut denominator = F64x2::splat(0.0);
+
+    let mut pairs = points.chunks_exact(2);
+    for (k, pair) in pairs.by_ref().enumerate() {
+        let weighted1 = basis[2 * k] * F64x2::splat(pair[0].weight());
+        let weighted2 = basis[2 * k + 1] * F64x2::splat(pair[1].weight());
+
+        let coordinates1 = pair[0].coordinates();
+        let coordinates2 = pair[1].coordinates();
+        for (d, value) in numerator.iter_mut().enumerate() {
+            let contrib = weighted1 * F64x2::splat(coordinates1[d])
+                + weighted2 * F64x2::splat(coordinates2[d]);
+            *value = *value + contrib;
+        }
+        denominator = denominator + (weighted1 + weighted2);
+    }
+
+    // Handle the remaining control point if degree is even
+    if let [cp] = pairs.remainder() {
+        let weighted = basis[points.len() - 1] * F64x2::splat(cp.weight());
+        for (value, coordinate) in numerator.iter_mut().zip(cp.coordinates()) {
+            *value = *value + weighted * F64x2::splat(coordinate);
+        }
+        denominator = denominator + weighted;
+    }
+
+    (numerator, denominator)
+}
+
+// Compute the derivatives of a rational curve up to the given order
+// Entry k holds the k-th derivative, entry 0 is the curve point itself
+pub fn rational_derivatives<const D: usize, P: WeightedPoint<D>>(
+    knots: &[f64],
+    degree: usize,
+    points: &[P],
+    u: f64,
+    order: usize,
+) -> Result<Vec<[f64; D]>, NurbsError> {
+    let span = checked_span(knots, degree, points.len(), u)?;
+    let basis_ders = basis_function_derivatives(knots, degree, span, u, order);
+
+    // Derivatives of the weighted (homogeneous) curve: (w*P, w)
+    // Derivatives above the degree vanish for the polynomial numerator and denominator
+    let mut homogeneous = vec![([0.0; D], 0.0); order + 1];
+    let control_points = &points[span - degree..=span];
+    for (h, basis) in homogeneous
+        .iter_mut()
+        .zip(basis_ders.iter())
+        .take(order.min(degree) + 1)
+    {
+        for (cp, n) in control_points.iter().zip(basis.iter()) {
+            let nw = n * cp.weight();
+            for (value, coordinate) in h.0.iter_mut().zip(cp.coordinates()) {
+                *value += nw * coordinate;
+            }
+            h.1 += nw;
+        }
+    }
+
+    let w0 = homogeneous[0].1;
+    if w0.abs() < 1e-10 {
+        return Err(NurbsError::ZeroWeight { u });
+    }
+
+    // Apply the quotient rule: C(k) = (A(k) - sum_{i=1..k} binom(k, i) w(i) C(k-i)) / w
+    let mut ders: Vec<[f64; D]> = vec![[0.0; D]; order + 1];
+    for k in 0..=order {
+        let mut v = homogeneous[k].0;
+        let mut binom = 1.0;
+        for i in 1..=k {
+            binom = binom * (k - i + 1) as f64 / i as f64;
+            let wi = homogeneous[i].1;
+            for (value, previous) in v.iter_mut().zip(ders[k - i]) {
+                *value -= binom * wi * previous;
+            }
+        }
+        ders[k] = v.map(|value| value / w0);
+    }
+
+    Ok(ders)
+}
+
+// Binomial coefficient as a float
+pub fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+// Generate a uniform unclamped knot vector on [0, 1] for the given number of control points
+// The domain [knots[degree], knots[num_points]] is [0, 1] with knots continuing evenly beyond it
+pub fn uniform_unclamped_knots(num_points: usize, degree: usize) -> Vec<f64> {
+    let spans = num_points.saturating_sub(degree).max(1) as f64;
+    (0..num_points + degree + 1)
+        .map(|i| (i as f64 - degree as f64) / spans)
+        .collect()
+}
+
+// Check that a knot vector suits a curve with num_points control points of the given degree
+pub fn validate_knots(knots: &[f64], degree: usize, num_points: usize) -> Result<(), KnotError> {
+    let expected = num_points + degree + 1;
+    if knots.len() != expected {
+        return Err(KnotError::WrongLength {
+            expected,
+            actual: knots.len(),
+        });
+    }
+
+    if let Some(index) = knots.iter().position(|knot| !knot.is_finite()) {
+        return Err(KnotError::NotFinite { index });
+    }
+
+    if let Some(index) = knots.windows(2).position(|pair| pair[1] < pair[0]) {
+        return Err(KnotError::Decreasing { index: index + 1 });
+    }
+
+    // Runs of equal knots, the ones at either end of the vector may be one longer.
+    // Degree 0 curves still allow single interior knots
+    let (first, last) = (knots[0], knots[knots.len() - 1]);
+    for run in knots.chunk_by(|a, b| a == b) {
+        let value = run[0];
+        let max = if value == first || value == last {
+            degree + 1
+        } else {
+            degree.max(1)
+        };
+        if run.len() > max {
+            return Err(KnotError::MultiplicityTooHigh {
+                value,
+                multiplicity: run.len(),
+                max,
+            });
+        }
+    }
+
+    if num_points == 0 || knots[degree] >= knots[num_points] {
+        return Err(KnotError::EmptyDomain);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,380 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core::{self, BatchScratch, WeightedPoint};
+use crate::NurbsError;
+
+// A control point in 3D space with its weight
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ControlPoint3 {
+    x: f64,
+    y: f64,
+    z: f64,
+    weight: f64,
+}
+
+#[wasm_bindgen]
+impl ControlPoint3 {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f64, y: f64, z: f64, weight: f64) -> ControlPoint3 {
+        ControlPoint3 { x, y, z, weight }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_x(&mut self, x: f64) {
+        self.x = x;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_y(&mut self, y: f64) {
+        self.y = y;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_z(&mut self, z: f64) {
+        self.z = z;
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight = weight;
+    }
+}
+
+impl WeightedPoint<3> for ControlPoint3 {
+    fn coordinates(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+// A NURBS curve in 3D space, sharing the evaluation core with the 2D curve
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct NurbsCurve3 {
+    control_points: Vec<ControlPoint3>,
+    knots: Vec<f64>,
+    degree: usize,
+}
+
+#[wasm_bindgen]
+impl NurbsCurve3 {
+    #[wasm_bindgen(constructor)]
+    pub fn new(degree: usize) -> NurbsCurve3 {
+        NurbsCurve3 {
+            control_points: Vec::new(),
+            knots: Vec::new(),
+            degree,
+        }
+    }
+
+    // Add a control point to the curve
+    pub fn add_control_point(&mut self, control_point: ControlPoint3) {
+        self.control_points.push(control_point);
+        self.update_knots();
+    }
+
+    // Set the knot vector manually, it must suit the current control points and degree
+    // The knots are regenerated uniformly again as soon as points are added or the degree changes
+    pub fn set_knots(&mut self, knots: Vec<f64>) -> Result<(), NurbsError> {
+        core::validate_knots(&knots, self.effective_degree(), self.control_points.len())?;
+        self.knots = knots;
+        Ok(())
+    }
+
+    // Get a copy of the knot vector
+    pub fn get_knots(&self) -> Vec<f64> {
+        self.knots.clone()
+    }
+
+    // Get the parameter domain [start, end] over which the curve is defined
+    pub fn domain(&self) -> Result<Box<[f64]>, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        Ok(Box::new([start, end]))
+    }
+
+    // Evaluate the curve at parameter u, returning [x, y, z]
+    pub fn evaluate(&self, u: f64) -> Result<Box<[f64]>, NurbsError> {
+        let point = core::evaluate(
+            &self.knots,
+            self.effective_degree(),
+            &self.control_points,
+            u,
+        )?;
+        Ok(Box::new(point))
+    }
+
+    // Evaluate the curve point and its derivatives up to the given order at parameter u
+    // Returns a flat array [x, y, z, dx, dy, dz, ...] with (order + 1) triples
+    pub fn derivatives(&self, u: f64, order: usize) -> Result<Box<[f64]>, NurbsError> {
+        let ders = core::rational_derivatives(
+            &self.knots,
+            self.effective_degree(),
+            &self.control_points,
+            u,
+            order,
+        )?;
+        Ok(ders.concat().into_boxed_slice())
+    }
+
+    // Generate evenly spaced points across the domain as a flat [x, y, z, ...] array
+    pub fn generate_points(&self, num_points: usize) -> Result<Box<[f64]>, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        let params = core::uniform_parameters(start, end, num_points);
+
+        let mut result = vec![0.0; params.len() * 3];
+        core::evaluate_batch(
+            &self.knots,
+            self.effective_degree(),
+            &self.control_points,
+            &params,
+            &mut result,
+            &mut BatchScratch::default(),
+        )?;
+        Ok(result.into_boxed_slice())
+    }
+
+    // Get the number of control points
+    pub fn num_control_points(&self) -> usize {
+        self.control_points.len()
+    }
+
+    // Get a control point by index
+    pub fn get_control_point(&self, index: usize) -> Result<ControlPoint3, NurbsError> {
+        self.control_points
+            .get(index)
+            .cloned()
+            .ok_or(NurbsError::IndexOutOfRange {
+                index,
+                len: self.control_points.len(),
+            })
+    }
+
+    // Update a control point at a specific index
+    pub fn update_control_point(
+        &mut self,
+        index: usize,
+        x: f64,
+        y: f64,
+        z: f64,
+        weight: f64,
+    ) -> Result<(), NurbsError> {
+        let len = self.control_points.len();
+        let cp = self
+            .control_points
+            .get_mut(index)
+            .ok_or(NurbsError::IndexOutOfRange { index, len })?;
+        *cp = ControlPoint3::new(x, y, z, weight);
+        Ok(())
+    }
+
+    // Get the degree of the curve as requested
+    pub fn get_degree(&self) -> usize {
+        self.degree
+    }
+
+    // Degree the curve is evaluated with, lowered to one less than the number of control points
+    // while there are too few points for the requested degree, as for NurbsCurve
+    pub fn effective_degree(&self) -> usize {
+        self.degree.min(self.control_points.len().saturating_sub(1))
+    }
+
+    // Change the degree while keeping the control points, the knots are regenerated uniformly
+    pub fn set_degree(&mut self, degree: usize) {
+        self.degree = degree;
+        self.update_knots();
+    }
+}
+
+impl NurbsCurve3 {
+    // First and last parameter of the curve domain, or why the curve cannot be evaluated
+    fn checked_range(&self) -> Result<(f64, f64), NurbsError> {
+        core::check_curve(
+            &self.knots,
+            self.effective_degree(),
+            self.control_points.len(),
+        )
+    }
+
+    fn update_knots(&mut self) {
+        self.knots =
+            core::uniform_clamped_knots(self.control_points.len(), self.effective_degree());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ControlPoint3, NurbsCurve3};
+    use crate::core::{self, WeightedPoint};
+    use crate::NurbsError;
+    use crate::{ControlPoint, NurbsCurve};
+
+    const POINTS: [(f64, f64, f64); 5] = [
+        (0.0, 0.0, 1.0),
+        (2.0, 5.0, 2.0),
+        (5.0, -1.0, 0.5),
+        (8.0, 4.0, 1.5),
+        (10.0, 0.0, 1.0),
+    ];
+
+    fn helix() -> NurbsCurve3 {
+        let mut curve = NurbsCurve3::new(3);
+        for (i, &(x, y, w)) in POINTS.iter().enumerate() {
+            curve.add_control_point(ControlPoint3::new(x, y, i as f64 * 1.5, w));
+        }
+        curve
+    }
+
+    #[test]
+    fn test_planar_curve_matches_2d() {
+        let mut planar = NurbsCurve3::new(3);
+        let mut curve = NurbsCurve::new(3);
+        for &(x, y, w) in POINTS.iter() {
+            planar.add_control_point(ControlPoint3::new(x, y, 0.0, w));
+            curve.add_control_point(ControlPoint::new(x, y, w));
+        }
+        assert_eq!(planar.get_knots(), curve.get_knots());
+
+        for i in 0..=20 {
+            let u = i as f64 / 20.0;
+            let expected = curve.derivatives(u, 2).unwrap();
+            let actual = planar.derivatives(u, 2).unwrap();
+            for k in 0..3 {
+                assert_eq!(actual[3 * k], expected[2 * k]);
+                assert_eq!(actual[3 * k + 1], expected[2 * k + 1]);
+                assert_eq!(actual[3 * k + 2], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        let curve = helix();
+        let h = 1e-6;
+
+        for &u in &[0.1, 0.45, 0.8] {
+            let ders = curve.derivatives(u, 1).unwrap();
+            let before = curve.evaluate(u - h).unwrap();
+            let after = curve.evaluate(u + h).unwrap();
+            for c in 0..3 {
+                let fd = (after[c] - before[c]) / (2.0 * h);
+                assert!(
+                    (ders[3 + c] - fd).abs() < 1e-5,
+                    "component {} at u = {}",
+                    c,
+                    u
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_points() {
+        let curve = helix();
+        let points = curve.generate_points(11).unwrap();
+        assert_eq!(points.len(), 33);
+
+        // Clamped ends interpolate the first and last control points
+        assert_eq!(&points[..3], &[0.0, 0.0, 0.0]);
+        assert_eq!(&points[30..], &[10.0, 0.0, 6.0]);
+
+        assert_eq!(
+            NurbsCurve3::new(3).generate_points(10),
+            Err(NurbsError::EmptyCurve)
+        );
+        assert!(NurbsCurve3::new(3).evaluate(0.5).is_err());
+    }
+
+    #[test]
+    fn test_degree_follows_point_count() {
+        let mut curve = NurbsCurve3::new(3);
+        curve.add_control_point(ControlPoint3::new(0.0, 0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint3::new(4.0, 2.0, 6.0, 1.0));
+
+        // Two points evaluate as a line until there are enough for the cubic
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.effective_degree(), 1);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(curve.evaluate(0.5).unwrap().to_vec(), vec![2.0, 1.0, 3.0]);
+        assert_eq!(
+            curve.derivatives(0.5, 1).unwrap()[3..].to_vec(),
+            vec![4.0, 2.0, 6.0]
+        );
+
+        let mut curve = helix();
+        curve.set_degree(2);
+        assert_eq!(curve.get_degree(), 2);
+        assert_eq!(
+            curve.get_knots(),
+            vec![0.0, 0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0]
+        );
+
+        // Beyond the point count the curve keeps working at the highest degree it supports
+        curve.set_degree(7);
+        assert_eq!(curve.effective_degree(), 4);
+        assert_eq!(curve.get_knots().len(), 10);
+        assert_eq!(
+            curve.generate_points(2).unwrap().to_vec(),
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 6.0]
+        );
+        assert!(curve.set_knots(vec![0.0; 12]).is_err());
+    }
+
+    // A point type with an extra coordinate, exercising the core in four dimensions
+    struct Point4([f64; 4], f64);
+
+    impl WeightedPoint<4> for Point4 {
+        fn coordinates(&self) -> [f64; 4] {
+            self.0
+        }
+
+        fn weight(&self) -> f64 {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_four_dimensional_core() {
+        let points = vec![
+            Point4([0.0, 0.0, 0.0, 1.0], 1.0),
+            Point4([1.0, 2.0, 3.0, 1.0], 1.0),
+            Point4([2.0, 4.0, 6.0, 1.0], 1.0),
+        ];
+        let knots = core::uniform_clamped_knots(points.len(), 2);
+
+        // Collinear points with unit weights give a linear map in every coordinate
+        let point = core::evaluate(&knots, 2, &points, 0.25).unwrap();
+        for (value, expected) in point.iter().zip([0.5, 1.0, 1.5, 1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+
+        let ders = core::rational_derivatives(&knots, 2, &points, 0.25, 1).unwrap();
+        assert_eq!(ders.len(), 2);
+        for (value, expected) in ders[1].iter().zip([2.0, 4.0, 6.0, 0.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core::binomial;
+use crate::{NurbsCurve, NurbsError};
+
+// Geometric tolerance, relative to the size of the curve, for removals that should be exact
+const EXACT_TOLERANCE: f64 = 1e-9;
+
+// Distinct knots of a curve together with the homogeneous control points of each Bezier segment
+type BezierSegments = (Vec<f64>, Vec<Vec<[f64; 3]>>);
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Raise the degree by t without changing the shape of the curve
+    // Fails if the curve cannot be evaluated or has degree 0, whose jumps would need interior
+    // knots of multiplicity t + 1 that a valid knot vector of degree t cannot hold
+    pub fn elevate_degree(&mut self, t: usize) -> Result<(), NurbsError> {
+        let (breakpoints, segments) = self.bezier_segments()?;
+        if t == 0 {
+            return Ok(());
+        }
+
+        let p = self.effective_degree();
+        if p == 0 {
+            return Err(NurbsError::InvalidDegree {
+                degree: p,
+                num_points: self.control_points.len(),
+            });
+        }
+        let elevated: Vec<Vec<[f64; 3]>> = segments
+            .iter()
+            .map(|segment| elevate_bezier(segment, t))
+            .collect();
+        let mut curve = NurbsCurve::from_bezier_segments(p + t, &breakpoints, &elevated);
+
+        // Interior knots return to their original multiplicity plus t, restoring the continuity
+        let limits: Vec<(f64, usize)> = breakpoints[1..breakpoints.len() - 1]
+            .iter()
+            .map(|&u| (u, p.saturating_sub(self.knot_multiplicity(u))))
+            .collect();
+        let tolerance = EXACT_TOLERANCE * (1.0 + self.coordinate_scale());
+        curve.remove_knots_within(self, &limits, tolerance);
+
+        *self = curve;
+        Ok(())
+    }
+
+    // Lower the degree by one if the curve moves by at most `tolerance`
+    // The deviation is bounded for the whole curve, not sampled, so the bound can reject a
+    // reduction whose actual deviation is somewhat smaller than the tolerance
+    // Fails, leaving the curve unchanged, if the bound exceeds the tolerance
+    pub fn reduce_degree(&mut self, tolerance: f64) -> Result<(), NurbsError> {
+        let p = self.effective_degree();
+        if p < 2 {
+            return Err(NurbsError::InvalidDegree {
+                degree: p,
+                num_points: self.control_points.len(),
+            });
+        }
+
+        let (breakpoints, segments) = self.bezier_segments()?;
+
+        let reduced: Vec<Vec<[f64; 3]>> = segments
+            .iter()
+            .map(|segment| reduce_bezier(segment))
+            .collect();
+        let mut curve = NurbsCurve::from_bezier_segments(p - 1, &breakpoints, &reduced);
+
+        // The segments only join with C0 continuity, check them before smoothing the joins
+        let error = reduction_error_bound(&segments, &breakpoints, &curve);
+        if error > tolerance {
+            return Err(NurbsError::ToleranceExceeded { error, tolerance });
+        }
+
+        // Remove knots towards the original continuity while the bound allows
+        let limits: Vec<(f64, usize)> = breakpoints[1..breakpoints.len() - 1]
+            .iter()
+            .map(|&u| {
+                let target = self.knot_multiplicity(u).saturating_sub(1).max(1);
+                (u, (p - 1).saturating_sub(target))
+            })
+            .collect();
+        curve.remove_knots_while(&limits, tolerance, |candidate| {
+            reduction_error_bound(&segments, &breakpoints, candidate) <= tolerance
+        });
+
+        *self = curve;
+        Ok(())
+    }
+}
+
+impl NurbsCurve {
+    // Distinct knots of the domain and the homogeneous Bezier control points of each span
+    fn bezier_segments(&self) -> Result<BezierSegments, NurbsError> {
+        let spans = self.knot_spans();
+        let segments: Vec<Vec<[f64; 3]>> = self
+            .decompose_to_bezier()?
+            .iter()
+            .map(|segment| segment.homogeneous_points())
+            .collect();
+
+        // The decomposition has one segment per span and fails without any
+        let mut breakpoints = vec![spans[0].0];
+        breakpoints.extend(spans.iter().map(|&(_, b)| b));
+        Ok((breakpoints, segments))
+    }
+
+    // Join Bezier segments of the given degree into a clamped curve with full-multiplicity interior knots
+    // The knots are locked, adding points must not regenerate them
+    fn from_bezier_segments(
+        degree: usize,
+        breakpoints: &[f64],
+        segments: &[Vec<[f64; 3]>],
+    ) -> NurbsCurve {
+        let mut knots = vec![breakpoints[0]; degree + 1];
+        for &u in &breakpoints[1..breakpoints.len() - 1] {
+            knots.extend(std::iter::repeat_n(u, degree));
+        }
+        knots.extend(std::iter::repeat_n(
+            breakpoints[breakpoints.len() - 1],
+            degree + 1,
+        ));
+
+        // Neighbouring segments share their end points
+        let mut points = segments[0].clone();
+        for segment in &segments[1..] {
+            points.extend_from_slice(&segment[1..]);
+        }
+
+        let mut curve = NurbsCurve::from_parts(Vec::new(), knots, degree);
+        curve.set_homogeneous_points(&points);
+        curve
+    }
+
+    // Largest absolute control point coordinate, used to scale tolerances
+    pub(crate) fn coordinate_scale(&self) -> f64 {
+        self.control_points
+            .iter()
+            .map(|cp| cp.x.abs().max(cp.y.abs()))
+            .fold(0.0, f64::max)
+    }
+}
+
+// Raise a Bezier segment from degree p to p + t
+fn elevate_bezier(points: &[[f64; 3]], t: usize) -> Vec<[f64; 3]> {
+    let p = points.len() - 1;
+    let q = p + t;
+
+    (0..=q)
+        .map(|i| {
+            let mut point = [0.0; 3];
+            let first = i.saturating_sub(t);
+            for (j, source_point) in points.iter().enumerate().take(p.min(i) + 1).skip(first) {
+                let coefficient = binomial(p, j) * binomial(t, i - j) / binomial(q, i);
+                for (value, source) in point.iter_mut().zip(source_point) {
+                    *value += coefficient * source;
+                }
+            }
+            point
+        })
+        .collect()
+}
+
+// Upper bound on the distance between the curve made of `segments` and a curve one degree lower
+// whose knots lie at the same breakpoints (the error bound of The NURBS Book, A5.11)
+// Raising the lower curve's segments back to the original degree puts both on the same Bernstein
+// basis, so the difference is bounded by the differences of their control points
+fn reduction_error_bound(
+    segments: &[Vec<[f64; 3]>],
+    breakpoints: &[f64],
+    lower: &NurbsCurve,
+) -> f64 {
+    let p = lower.effective_degree();
+
+    // Cut the lower curve at every breakpoint so its segments line up with the original ones
+    let mut refined = lower.clone();
+    let extra: Vec<f64> = breakpoints[1..breakpoints.len() - 1]
+        .iter()
+        .flat_map(|&u| std::iter::repeat_n(u, p.saturating_sub(lower.knot_multiplicity(u))))
+        .collect();
+    if !extra.is_empty() && refined.refine_knots(&extra).is_err() {
+        return f64::INFINITY;
+    }
+    let lower_segments = match refined.bezier_segments() {
+        Ok((lower_breakpoints, lower_segments)) if lower_breakpoints == breakpoints => {
+            lower_segments
+        }
+        _ => return f64::INFINITY,
+    };
+
+    segments
+        .iter()
+        .zip(&lower_segments)
+        .map(|(segment, lower)| bezier_distance_bound(segment, &elevate_bezier(lower, 1)))
+        .fold(0.0, f64::max)
+}
+
+// Upper bound on the distance between two rational Bezier segments of the same degree
+// With C = A / w and C' = A' / w', C' - C = ((A' - A) - C (w' - w)) / w'. Measured from a point
+// c of the first segment, |C - c| stays within the hull of its points and w' above its smallest
+// weight, so the largest control point difference bounds the numerator
+fn bezier_distance_bound(first: &[[f64; 3]], second: &[[f64; 3]]) -> f64 {
+    // The hull arguments need positive weights
+    if first.iter().chain(second).any(|point| point[2] <= 0.0) {
+        return f64::INFINITY;
+    }
+
+    let c = [first[0][0] / first[0][2], first[0][1] / first[0][2]];
+    let radius = first
+        .iter()
+        .map(|point| (point[0] / point[2] - c[0]).hypot(point[1] / point[2] - c[1]))
+        .fold(0.0, f64::max);
+    let min_weight = second
+        .iter()
+        .map(|point| point[2])
+        .fold(f64::INFINITY, f64::min);
+
+    let numerator = first
+        .iter()
+        .zip(second)
+        .map(|(a, b)| {
+            // Homogeneous differences with the origin moved to c
+            let dw = b[2] - a[2];
+            let dx = (b[0] - c[0] * b[2]) - (a[0] - c[0] * a[2]);
+            let dy = (b[1] - c[1] * b[2]) - (a[1] - c[1] * a[2]);
+            dx.hypot(dy) + radius * dw.abs()
+        })
+        .fold(0.0, f64::max);
+
+    numerator / min_weight
+}
+
+// Approximate a Bezier segment of degree p by one of degree p - 1 with the same end points
+// Interior points are solved from both ends and meet in the middle
+fn reduce_bezier(points: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    let p = points.len() - 1;
+    let r = (p - 1) / 2;
+    let alpha = |i: usize| i as f64 / p as f64;
+
+    let mut reduced = vec![[0.0; 3]; p];
+    reduced[0] = points[0];
+    reduced[p - 1] = points[p];
+
+    let from_left = |reduced: &[[f64; 3]], i: usize| {
+        let a = alpha(i);
+        let mut point = [0.0; 3];
+        for (c, value) in point.iter_mut().enumerate() {
+            *value = (points[i][c] - a * reduced[i - 1][c]) / (1.0 - a);
+        }
+        point
+    };
+    let from_right = |reduced: &[[f64; 3]], i: usize| {
+        let a = alpha(i + 1);
+        let mut point = [0.0; 3];
+        for (c, value) in point.iter_mut().enumerate() {
+            *value = (points[i + 1][c] - (1.0 - a) * reduced[i + 1][c]) / a;
+        }
+        point
+    };
+
+    let left_end = if p % 2 == 1 { r.saturating_sub(1) } else { r };
+    for i in 1..=left_end {
+        reduced[i] = from_left(&reduced, i);
+    }
+    for i in (r + 1..p - 1).rev() {
+        reduced[i] = from_right(&reduced, i);
+    }
+
+    // Odd degrees average both solutions for the middle point
+    if p % 2 == 1 && r >= 1 {
+        let left = from_left(&reduced, r);
+        let right = from_right(&reduced, r);
+        for c in 0..3 {
+            reduced[r][c] = 0.5 * (left[c] + right[c]);
+        }
+    }
+
+    reduced
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+    fn weighted_cubic() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 5.0, 2.0));
+        curve.add_control_point(ControlPoint::new(5.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(8.0, 4.0, 1.5));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(12.0, 3.0, 0.8));
+        curve
+    }
+
+    fn max_distance(a: &NurbsCurve, b: &NurbsCurve) -> f64 {
+        (0..=400)
+            .map(|i| {
+                let u = i as f64 / 400.0;
+                let pa = a.evaluate(u).unwrap();
+                let pb = b.evaluate(u).unwrap();
+                (pa.x() - pb.x()).hypot(pa.y() - pb.y())
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_elevate_degree() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        assert!(curve.elevate_degree(2).is_ok());
+        assert_eq!(curve.get_degree(), 5);
+
+        // Each of the three spans gains two control points, interior knots keep their continuity
+        assert_eq!(curve.num_control_points(), 12);
+        assert_eq!(curve.get_knots().len(), 18);
+        assert!(max_distance(&original, &curve) < 1e-10);
+    }
+
+    #[test]
+    fn test_elevate_then_reduce_round_trip() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        assert!(curve.elevate_degree(1).is_ok());
+        assert!(curve.reduce_degree(1e-8).is_ok());
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.num_control_points(), 6);
+        assert!(max_distance(&original, &curve) < 1e-8);
+    }
+
+    #[test]
+    fn test_reduce_degree_respects_tolerance() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        // A genuine cubic cannot become a quadratic within a tight tolerance
+        let Err(NurbsError::ToleranceExceeded { error, .. }) = curve.reduce_degree(1e-6) else {
+            panic!("the reduction should exceed the tolerance");
+        };
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.num_control_points(), 6);
+
+        // The reported bound is enough to allow the approximate reduction
+        let tolerance = error;
+        assert!(curve.reduce_degree(tolerance).is_ok());
+        assert_eq!(curve.get_degree(), 2);
+        assert!(max_distance(&original, &curve) <= tolerance);
+    }
+
+    #[test]
+    fn test_cubic_to_quintic_and_back() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        curve.elevate_degree(2).unwrap();
+        assert_eq!(curve.get_degree(), 5);
+
+        // Lowering by two degrees takes two calls, each within the tolerance
+        curve.reduce_degree(1e-8).unwrap();
+        curve.reduce_degree(1e-8).unwrap();
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.num_control_points(), 6);
+        assert!(max_distance(&original, &curve) < 1e-8);
+
+        // Going below the original degree again needs a looser tolerance
+        assert!(matches!(
+            curve.reduce_degree(1e-8),
+            Err(NurbsError::ToleranceExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_elevate_step_function_is_rejected() {
+        let mut curve = NurbsCurve::new(0);
+        for (x, y) in [(0.0, 0.0), (2.0, 0.909), (4.0, 0.2)] {
+            curve.add_control_point(ControlPoint::new(x, y, 1.0));
+        }
+        let knots = curve.get_knots();
+
+        assert_eq!(
+            curve.elevate_degree(1),
+            Err(NurbsError::InvalidDegree {
+                degree: 0,
+                num_points: 3
+            })
+        );
+        assert_eq!(curve.get_degree(), 0);
+        assert_eq!(curve.get_knots(), knots);
+        assert_eq!(curve.elevate_degree(0), Ok(()));
+    }
+
+    #[test]
+    fn test_degree_change_on_empty_curve() {
+        let mut curve = NurbsCurve::new(3);
+        assert!(curve.elevate_degree(1).is_err());
+        assert!(curve.reduce_degree(1.0).is_err());
+    }
+}
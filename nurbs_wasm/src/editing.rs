@@ -0,0 +1,256 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+// Structural edits of the control polygon
+// Knots follow the knot strategy: generated strategies regenerate them after every edit,
+// Locked knots are kept and must be replaced with set_knots once the number of points changes.
+// On a periodic curve the indices address the distinct points of the loop, the wrapped copies
+// follow automatically
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Insert a control point before `index`, an index equal to the point count appends
+    pub fn insert_control_point(
+        &mut self,
+        index: usize,
+        control_point: ControlPoint,
+    ) -> Result<(), NurbsError> {
+        let mut points = self.distinct_points();
+        if index > points.len() {
+            return Err(NurbsError::IndexOutOfRange {
+                index,
+                len: points.len(),
+            });
+        }
+
+        points.insert(index, control_point);
+        self.replace_control_points(points);
+        Ok(())
+    }
+
+    // Remove the control point at `index` and return it
+    // A periodic loop left with a single point opens up
+    pub fn remove_control_point(&mut self, index: usize) -> Result<ControlPoint, NurbsError> {
+        let mut points = self.distinct_points();
+        if index >= points.len() {
+            return Err(NurbsError::IndexOutOfRange {
+                index,
+                len: points.len(),
+            });
+        }
+
+        let removed = points.remove(index);
+        self.replace_control_points(points);
+        Ok(removed)
+    }
+
+    // Move the control point at `from` so it ends up at index `to`, shifting the points between
+    pub fn move_control_points(&mut self, from: usize, to: usize) -> Result<(), NurbsError> {
+        let mut points = self.distinct_points();
+        let len = points.len();
+        if let Some(index) = [from, to].into_iter().find(|&index| index >= len) {
+            return Err(NurbsError::IndexOutOfRange { index, len });
+        }
+
+        let point = points.remove(from);
+        points.insert(to, point);
+        self.replace_control_points(points);
+        Ok(())
+    }
+
+    // Replace all control points with the ones given as separate coordinate and weight arrays
+    // Fails, leaving the curve unchanged, if the arrays differ in length
+    pub fn set_control_points_flat(
+        &mut self,
+        xs: &[f64],
+        ys: &[f64],
+        ws: &[f64],
+    ) -> Result<(), NurbsError> {
+        if xs.len() != ys.len() || xs.len() != ws.len() {
+            return Err(NurbsError::InvalidArgument(
+                "xs, ys and ws must have the same length",
+            ));
+        }
+
+        let points = xs
+            .iter()
+            .zip(ys)
+            .zip(ws)
+            .map(|((&x, &y), &w)| ControlPoint::new(x, y, w))
+            .collect();
+        self.replace_control_points(points);
+        Ok(())
+    }
+
+    // Remove all control points and knots, keeping the degree
+    // The curve leaves periodic mode, Locked knots have nothing left to lock and go back to
+    // uniform clamped generation
+    pub fn clear(&mut self) {
+        self.control_points.clear();
+        self.knots.clear();
+        self.periodic = false;
+        self.unlock_knots();
+    }
+}
+
+impl NurbsCurve {
+    // Control points without the wrapped copies of a periodic curve
+    pub(crate) fn distinct_points(&self) -> Vec<ControlPoint> {
+        self.control_points[..self.distinct_control_points()].to_vec()
+    }
+
+    // Take new distinct control points and bring the knots in line with the edit policy
+    pub(crate) fn replace_control_points(&mut self, points: Vec<ControlPoint>) {
+        if self.periodic && points.len() >= 2 {
+            self.wrap_periodic(points);
+            return;
+        }
+
+        self.control_points = points;
+        self.periodic = false;
+        self.update_knots();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, KnotStrategy, NurbsCurve, NurbsError};
+
+    fn curve(degree: usize, xs: &[f64]) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(degree);
+        for &x in xs {
+            curve.add_control_point(ControlPoint::new(x, 0.0, 1.0));
+        }
+        curve
+    }
+
+    // Knots of a curve built point by point, for comparison with edited curves
+    fn knots_of(degree: usize, xs: &[f64]) -> Vec<f64> {
+        curve(degree, xs).get_knots()
+    }
+
+    fn xs(curve: &NurbsCurve) -> Vec<f64> {
+        (0..curve.num_control_points())
+            .map(|i| curve.get_control_point(i).unwrap().x())
+            .collect()
+    }
+
+    #[test]
+    fn test_insert_and_remove_control_points() {
+        let mut curve = curve(2, &[0.0, 1.0, 3.0]);
+        curve
+            .insert_control_point(2, ControlPoint::new(2.0, 0.0, 1.0))
+            .unwrap();
+        curve
+            .insert_control_point(4, ControlPoint::new(4.0, 0.0, 1.0))
+            .unwrap();
+        assert_eq!(xs(&curve), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(curve.get_knots(), knots_of(2, &[0.0, 1.0, 2.0, 3.0, 4.0]));
+
+        let removed = curve.remove_control_point(1).unwrap();
+        assert_eq!(removed.x(), 1.0);
+        assert_eq!(xs(&curve), vec![0.0, 2.0, 3.0, 4.0]);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+
+        assert_eq!(
+            curve.insert_control_point(5, ControlPoint::new(0.0, 0.0, 1.0)),
+            Err(NurbsError::IndexOutOfRange { index: 5, len: 4 })
+        );
+        assert!(curve.remove_control_point(4).is_err());
+        assert_eq!(curve.num_control_points(), 4);
+    }
+
+    #[test]
+    fn test_move_control_points() {
+        let mut curve = curve(1, &[0.0, 1.0, 5.0, 6.0]);
+        curve.set_knot_strategy(KnotStrategy::ChordLength);
+
+        curve.move_control_points(3, 0).unwrap();
+        assert_eq!(xs(&curve), vec![6.0, 0.0, 1.0, 5.0]);
+        // Chord length knots follow the new order
+        let knots = curve.get_knots();
+        assert!((knots[2] - 6.0 / 11.0).abs() < 1e-12);
+        assert!((knots[3] - 7.0 / 11.0).abs() < 1e-12);
+
+        curve.move_control_points(1, 2).unwrap();
+        assert_eq!(xs(&curve), vec![6.0, 1.0, 0.0, 5.0]);
+        assert_eq!(
+            curve.move_control_points(0, 4),
+            Err(NurbsError::IndexOutOfRange { index: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn test_set_control_points_flat_and_clear() {
+        let mut curve = NurbsCurve::new(2);
+        curve
+            .set_control_points_flat(&[0.0, 1.0, 2.0], &[0.0, 2.0, 0.0], &[1.0, 2.0, 1.0])
+            .unwrap();
+        assert_eq!(curve.num_control_points(), 3);
+        assert_eq!(curve.get_control_point(1).unwrap().weight(), 2.0);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+        assert!(matches!(
+            curve.set_control_points_flat(&[0.0, 1.0], &[0.0], &[1.0, 1.0]),
+            Err(NurbsError::InvalidArgument(_))
+        ));
+        assert_eq!(curve.num_control_points(), 3);
+
+        curve.clear();
+        assert_eq!(curve.num_control_points(), 0);
+        assert!(curve.get_knots().is_empty());
+        assert_eq!(curve.get_degree(), 2);
+        assert_eq!(curve.evaluate(0.5).unwrap_err(), NurbsError::EmptyCurve);
+    }
+
+    #[test]
+    fn test_locked_knots_are_kept() {
+        let mut curve = curve(1, &[0.0, 1.0, 2.0]);
+        let knots = vec![0.0, 0.0, 0.25, 1.0, 1.0];
+        curve.set_knots(knots.clone()).unwrap();
+
+        // Reordering keeps the point count, so the knots still fit
+        curve.move_control_points(0, 2).unwrap();
+        assert_eq!(curve.get_knots(), knots);
+        assert!(curve.evaluate(0.5).is_ok());
+
+        // Changing the count leaves the knots for the caller to replace
+        curve.remove_control_point(0).unwrap();
+        assert_eq!(curve.get_knots(), knots);
+        assert!(matches!(
+            curve.evaluate(0.5),
+            Err(NurbsError::InvalidKnots(_))
+        ));
+        curve.set_knots(vec![0.0, 0.0, 1.0, 1.0]).unwrap();
+        assert!(curve.evaluate(0.5).is_ok());
+
+        curve.clear();
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::UniformClamped);
+    }
+
+    #[test]
+    fn test_editing_periodic_curve() {
+        let mut curve = curve(2, &[0.0, 4.0, 4.0, 1.0]);
+        curve.set_periodic(true).unwrap();
+        assert_eq!(curve.num_control_points(), 6);
+
+        // Wrapped copies are not addressable and follow the edits
+        assert!(curve.remove_control_point(4).is_err());
+        curve.remove_control_point(0).unwrap();
+        assert!(curve.is_periodic());
+        assert_eq!(xs(&curve), vec![4.0, 4.0, 1.0, 4.0, 4.0]);
+
+        curve
+            .insert_control_point(3, ControlPoint::new(-1.0, 0.0, 1.0))
+            .unwrap();
+        assert_eq!(xs(&curve), vec![4.0, 4.0, 1.0, -1.0, 4.0, 4.0]);
+        assert!(curve.is_closed());
+
+        // Down to a single point the loop opens up
+        for _ in 0..3 {
+            curve.remove_control_point(0).unwrap();
+        }
+        assert!(!curve.is_periodic());
+        assert_eq!(curve.num_control_points(), 1);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use wasm_bindgen::prelude::*;
+
+// Reasons an operation on a curve or surface fails
+#[derive(Clone, Debug, PartialEq)]
+pub enum NurbsError {
+    // The curve or surface has no control points
+    EmptyCurve,
+    // The degree needs more control points than there are, or the operation does not support it
+    InvalidDegree { degree: usize, num_points: usize },
+    // The knot vector does not suit the control points and degree
+    InvalidKnots(KnotError),
+    // The weighted basis functions sum to zero at u, the curve has no point there
+    ZeroWeight { u: f64 },
+    // The weighted basis functions of a surface sum to zero at (u, v)
+    SurfaceZeroWeight { u: f64, v: f64 },
+    // The curve has zero speed at u, a cusp where tangent and curvature are undefined
+    UndefinedTangent { u: f64 },
+    // The parameter is not a number or lies outside the domain where the operation needs it inside
+    ParameterOutOfDomain { u: f64, start: f64, end: f64 },
+    // No control point at that index
+    IndexOutOfRange { index: usize, len: usize },
+    // An argument is outside the range the operation accepts
+    InvalidArgument(&'static str),
+    // The result would deviate from the input by more than the tolerance
+    ToleranceExceeded { error: f64, tolerance: f64 },
+    // The fitting system has no unique solution
+    SingularSystem,
+}
+
+impl NurbsError {
+    // Name of the variant, exposed to JS as the `kind` of the thrown error
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NurbsError::EmptyCurve => "EmptyCurve",
+            NurbsError::InvalidDegree { .. } => "InvalidDegree",
+            NurbsError::InvalidKnots(_) => "InvalidKnots",
+            NurbsError::ZeroWeight { .. } => "ZeroWeight",
+            NurbsError::SurfaceZeroWeight { .. } => "SurfaceZeroWeight",
+            NurbsError::UndefinedTangent { .. } => "UndefinedTangent",
+            NurbsError::ParameterOutOfDomain { .. } => "ParameterOutOfDomain",
+            NurbsError::IndexOutOfRange { .. } => "IndexOutOfRange",
+            NurbsError::InvalidArgument(_) => "InvalidArgument",
+            NurbsError::ToleranceExceeded { .. } => "ToleranceExceeded",
+            NurbsError::SingularSystem => "SingularSystem",
+        }
+    }
+}
+
+impl fmt::Display for NurbsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NurbsError::EmptyCurve => write!(f, "there are no control points"),
+            NurbsError::InvalidDegree { degree, num_points } => write!(
+                f,
+                "degree {} does not suit {} control points",
+                degree, num_points
+            ),
+            NurbsError::InvalidKnots(error) => write!(f, "invalid knot vector: {}", error),
+            NurbsError::ZeroWeight { u } => {
+                write!(f, "the weights sum to zero at parameter {}", u)
+            }
+            NurbsError::SurfaceZeroWeight { u, v } => {
+                write!(f, "the weights sum to zero at parameters ({}, {})", u, v)
+            }
+            NurbsError::UndefinedTangent { u } => {
+                write!(f, "the curve has no tangent at the cusp at parameter {}", u)
+            }
+            NurbsError::ParameterOutOfDomain { u, start, end } => write!(
+                f,
+                "parameter {} is outside the domain [{}, {}]",
+                u, start, end
+            ),
+            NurbsError::IndexOutOfRange { index, len } => write!(
+                f,
+                "index {} is out of range for {} control points",
+                index, len
+            ),
+            NurbsError::InvalidArgument(reason) => write!(f, "invalid argument: {}", reason),
+            NurbsError::ToleranceExceeded { error, tolerance } => write!(
+                f,
+                "the deviation {} exceeds the tolerance {}",
+                error, tolerance
+            ),
+            NurbsError::SingularSystem => write!(f, "the fitting system has no unique solution"),
+        }
+    }
+}
+
+impl std::error::Error for NurbsError {}
+
+impl From<KnotError> for NurbsError {
+    fn from(error: KnotError) -> NurbsError {
+        NurbsError::InvalidKnots(error)
+    }
+}
+
+// Thrown in JS as an Error named NurbsError, with the variant name in its `kind` property
+impl From<NurbsError> for JsValue {
+    fn from(error: NurbsError) -> JsValue {
+        let js_error = js_sys::Error::new(&error.to_string());
+        js_error.set_name("NurbsError");
+        let _ = js_sys::Reflect::set(&js_error, &"kind".into(), &error.kind().into());
+        js_error.into()
+    }
+}
+
+// Reasons a knot vector is rejected
+#[derive(Clone, Debug, PartialEq)]
+pub enum KnotError {
+    // The vector must have one knot per control point plus degree + 1
+    WrongLength {
+        expected: usize,
+        actual: usize,
+    },
+    // Knots must be finite numbers
+    NotFinite {
+        index: usize,
+    },
+    // Knots must never decrease
+    Decreasing {
+        index: usize,
+    },
+    // A knot may repeat at most degree times inside the domain and degree + 1 times at the ends
+    MultiplicityTooHigh {
+        value: f64,
+        multiplicity: usize,
+        max: usize,
+    },
+    // The domain [knots[degree], knots[n + 1]] must not be empty
+    EmptyDomain,
+}
+
+impl fmt::Display for KnotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnotError::WrongLength { expected, actual } => {
+                write!(f, "expected {} knots, got {}", expected, actual)
+            }
+            KnotError::NotFinite { index } => write!(f, "knot {} is not a finite number", index),
+            KnotError::Decreasing { index } => {
+                write!(f, "knot {} is smaller than the knot before it", index)
+            }
+            KnotError::MultiplicityTooHigh {
+                value,
+                multiplicity,
+                max,
+            } => write!(
+                f,
+                "knot {} is repeated {} times, at most {} allowed",
+                value, multiplicity, max
+            ),
+            KnotError::EmptyDomain => write!(f, "the knots leave the curve with an empty domain"),
+        }
+    }
+}
+
+impl std::error::Error for KnotError {}
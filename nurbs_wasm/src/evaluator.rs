@@ -0,0 +1,200 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core::{self, BatchScratch};
+use crate::{NurbsCurve, NurbsError};
+
+// Reusable evaluation state for render loops
+// Keeps the basis scratch space and the parameter and point buffers between calls. Once they have
+// grown to the highest degree and sample count in use, evaluating allocates nothing, so a frame
+// loop does not churn the allocator, which shows most with the dlmalloc feature.
+// points_ptr points into wasm memory so JS can view the samples without copying, views must be
+// recreated if the wasm memory grows and are only valid until the next generate_points call
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct CurveEvaluator {
+    scratch: BatchScratch,
+    params: Vec<f64>,
+    points: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl CurveEvaluator {
+    // Evaluator with scratch space for curves up to `degree`, a higher degree grows it once
+    #[wasm_bindgen(constructor)]
+    pub fn new(degree: usize) -> CurveEvaluator {
+        CurveEvaluator {
+            scratch: BatchScratch::with_degree(degree),
+            ..CurveEvaluator::default()
+        }
+    }
+
+    // Write the point at u to `out` as [x, y], the same point evaluate returns
+    pub fn evaluate_into(
+        &mut self,
+        curve: &NurbsCurve,
+        u: f64,
+        out: &mut [f64],
+    ) -> Result<(), NurbsError> {
+        let u = curve.wrap_parameter(u);
+        curve.evaluate_batch(&[u], out, &mut self.scratch)
+    }
+
+    // Write the points at all parameters to `out` as [x0, y0, x1, y1, ...], as evaluate_many
+    pub fn evaluate_many_into(
+        &mut self,
+        curve: &NurbsCurve,
+        params: &[f64],
+        out: &mut [f64],
+    ) -> Result<(), NurbsError> {
+        if !curve.periodic {
+            return curve.evaluate_batch(params, out, &mut self.scratch);
+        }
+
+        // Periodic curves map outside parameters back into the domain, in the reused buffer
+        self.params.clear();
+        self.params
+            .extend(params.iter().map(|&u| curve.wrap_parameter(u)));
+        curve.evaluate_batch(&self.params, out, &mut self.scratch)
+    }
+
+    // Sample evenly spaced points into the evaluator's own buffer, as generate_points
+    // Read them with points_ptr and point_count, a failed call leaves no points
+    pub fn generate_points(
+        &mut self,
+        curve: &NurbsCurve,
+        num_points: usize,
+    ) -> Result<(), NurbsError> {
+        self.points.clear();
+        let (start, end) = curve.checked_range()?;
+
+        let count = num_points.max(2);
+        self.params.resize(count, 0.0);
+        core::fill_uniform_parameters(start, end, &mut self.params);
+
+        self.points.resize(2 * count, 0.0);
+        let result = curve.evaluate_batch(&self.params, &mut self.points, &mut self.scratch);
+        if result.is_err() {
+            self.points.clear();
+        }
+        result
+    }
+
+    pub fn points_ptr(&self) -> *const f64 {
+        self.points.as_ptr()
+    }
+
+    // Number of points from the last generate_points call, two floats each
+    pub fn point_count(&self) -> usize {
+        self.points.len() / 2
+    }
+}
+
+impl CurveEvaluator {
+    // Points from the last generate_points call as [x0, y0, x1, y1, ...]
+    pub fn points(&self) -> &[f64] {
+        &self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CurveEvaluator;
+    use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+    fn curve(degree: usize, num_points: usize) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(degree);
+        for i in 0..num_points {
+            let x = i as f64;
+            let weight = 1.0 + 0.5 * (i % 2) as f64;
+            curve.add_control_point(ControlPoint::new(x, (x * 0.9).cos() * 3.0, weight));
+        }
+        curve
+    }
+
+    #[test]
+    fn test_evaluator_matches_curve_evaluation() {
+        let mut evaluator = CurveEvaluator::new(3);
+        for degree in 1..=4 {
+            let curve = curve(degree, 7);
+            let params = [0.0, 0.3, 0.31, 0.8, 0.2, 1.0, 0.7];
+
+            let mut out = [0.0; 14];
+            evaluator
+                .evaluate_many_into(&curve, &params, &mut out)
+                .unwrap();
+            assert_eq!(out[..], curve.evaluate_many(&params).unwrap()[..]);
+
+            let mut point = [0.0; 2];
+            evaluator.evaluate_into(&curve, 0.45, &mut point).unwrap();
+            let expected = curve.evaluate(0.45).unwrap();
+            assert_eq!(point, [expected.x(), expected.y()]);
+
+            evaluator.generate_points(&curve, 20).unwrap();
+            let generated = curve.generate_points(20).unwrap();
+            assert_eq!(evaluator.point_count(), 20);
+            for (flat, point) in evaluator.points().chunks_exact(2).zip(&generated) {
+                assert_eq!(flat, [point.x(), point.y()]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_periodic_parameters_wrap() {
+        let mut curve = curve(3, 6);
+        curve.set_periodic(true).unwrap();
+        let mut evaluator = CurveEvaluator::new(3);
+
+        let params = [-0.25, 0.5, 1.75];
+        let mut out = [0.0; 6];
+        evaluator
+            .evaluate_many_into(&curve, &params, &mut out)
+            .unwrap();
+        for (i, &u) in params.iter().enumerate() {
+            let point = curve.evaluate(u).unwrap();
+            assert_eq!(out[2 * i..2 * i + 2], [point.x(), point.y()]);
+        }
+
+        let mut point = [0.0; 2];
+        evaluator.evaluate_into(&curve, 7.5, &mut point).unwrap();
+        let expected = curve.evaluate(7.5).unwrap();
+        assert_eq!(point, [expected.x(), expected.y()]);
+    }
+
+    #[test]
+    fn test_buffers_are_reused() {
+        let curve = curve(3, 12);
+        let mut evaluator = CurveEvaluator::new(3);
+
+        evaluator.generate_points(&curve, 256).unwrap();
+        let points = evaluator.points_ptr();
+        let params = evaluator.params.as_ptr();
+
+        // Fewer or as many samples fit in the buffers of the first call
+        for num_points in [256, 64, 200] {
+            evaluator.generate_points(&curve, num_points).unwrap();
+            assert_eq!(evaluator.point_count(), num_points);
+            assert_eq!(evaluator.points_ptr(), points);
+            assert_eq!(evaluator.params.as_ptr(), params);
+        }
+    }
+
+    #[test]
+    fn test_evaluator_errors() {
+        let curve = curve(2, 5);
+        let mut evaluator = CurveEvaluator::new(2);
+
+        let mut short = [0.0; 3];
+        assert!(matches!(
+            evaluator.evaluate_many_into(&curve, &[0.1, 0.2], &mut short),
+            Err(NurbsError::InvalidArgument(_))
+        ));
+        assert!(evaluator.evaluate_into(&curve, 0.5, &mut short).is_err());
+
+        evaluator.generate_points(&curve, 8).unwrap();
+        assert_eq!(
+            evaluator.generate_points(&NurbsCurve::new(2), 8),
+            Err(NurbsError::EmptyCurve)
+        );
+        assert_eq!(evaluator.point_count(), 0);
+    }
+}
@@ -0,0 +1,765 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core;
+use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+// Pivots smaller than this make a fitting system singular
+const MIN_PIVOT: f64 = 1e-14;
+
+// Upper bound on refinement rounds in fit_to_tolerance, each round adds at least one knot
+const MAX_FIT_ROUNDS: usize = 64;
+
+// How parameter values are assigned to the data points of a fit
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parameterization {
+    // Evenly spaced parameters, ignoring the point spacing
+    Uniform,
+    // Parameters proportional to the distance between consecutive points
+    ChordLength,
+    // Parameters proportional to the square root of that distance, better for sharp turns
+    Centripetal,
+}
+
+// Result of fitting a curve to data points
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CurveFit {
+    curve: NurbsCurve,
+    max_error: f64,
+}
+
+#[wasm_bindgen]
+impl CurveFit {
+    // The fitted curve
+    #[wasm_bindgen(getter)]
+    pub fn curve(&self) -> NurbsCurve {
+        self.curve.clone()
+    }
+
+    // Largest distance between a data point and the curve point at its parameter
+    #[wasm_bindgen(getter)]
+    pub fn max_error(&self) -> f64 {
+        self.max_error
+    }
+}
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Build a curve of the given degree passing through the points [x0, y0, x1, y1, ...]
+    // The curve is defined on [0, 1] with knots averaged from the point parameters
+    // Fails if there are fewer than degree + 1 points or consecutive points coincide
+    pub fn interpolate(
+        points: Vec<f64>,
+        degree: usize,
+        parameterization: Parameterization,
+    ) -> Result<NurbsCurve, NurbsError> {
+        let points = pairs(&points)?;
+        check_fit_degree(degree, points.len(), degree + 1)?;
+        let n = points.len() - 1;
+
+        let params = parameters(&points, parameterization);
+        let knots = averaged_knots(&params, degree, 0);
+
+        // One row per point, with the basis functions at its parameter
+        let rows: Vec<(usize, Vec<f64>)> = params
+            .iter()
+            .map(|&u| basis_row(&knots, degree, n + 1, u))
+            .collect();
+
+        let control_points = BandedMatrix::from_rows(&rows)?.solve(&points)?;
+        Ok(curve_from(control_points, knots, degree))
+    }
+
+    // Like interpolate, additionally matching the derivatives [dx, dy] at both ends
+    // The tangents are derivatives on the [0, 1] domain, a unit direction scaled by the
+    // total chord length of the points is a good starting point
+    // Fails for degrees below 2, whose end segments leave no room for a separate tangent
+    pub fn interpolate_with_end_tangents(
+        points: Vec<f64>,
+        degree: usize,
+        parameterization: Parameterization,
+        start_tangent: Vec<f64>,
+        end_tangent: Vec<f64>,
+    ) -> Result<NurbsCurve, NurbsError> {
+        let points = pairs(&points)?;
+        let (start_tangent, end_tangent) = match (&start_tangent[..], &end_tangent[..]) {
+            (&[sx, sy], &[ex, ey]) => ([sx, sy], [ex, ey]),
+            _ => {
+                return Err(NurbsError::InvalidArgument(
+                    "the end tangents must be [dx, dy] pairs",
+                ))
+            }
+        };
+
+        // Two extra control points carry the tangents
+        check_fit_degree(degree, points.len(), 2.max(degree.saturating_sub(1)))?;
+        if degree < 2 {
+            return Err(NurbsError::InvalidDegree {
+                degree,
+                num_points: points.len(),
+            });
+        }
+        let n = points.len() - 1;
+
+        let params = parameters(&points, parameterization);
+        let knots = averaged_knots(&params, degree, 1);
+        let num_control_points = n + 3;
+
+        // P1 - P0 and P(n+2) - P(n+1) are fixed by the end derivatives
+        let start_scale = knots[degree + 1] / degree as f64;
+        let end_scale = (1.0 - knots[num_control_points - 1]) / degree as f64;
+
+        let mut rows = Vec::with_capacity(num_control_points);
+        let mut rhs = Vec::with_capacity(num_control_points);
+        rows.push((0, vec![1.0]));
+        rhs.push(points[0]);
+        rows.push((0, vec![-1.0, 1.0]));
+        rhs.push(start_tangent.map(|d| d * start_scale));
+        for (point, &u) in points.iter().zip(&params).skip(1).take(n - 1) {
+            rows.push(basis_row(&knots, degree, num_control_points, u));
+            rhs.push(*point);
+        }
+        rows.push((n + 1, vec![-1.0, 1.0]));
+        rhs.push(end_tangent.map(|d| d * end_scale));
+        rows.push((n + 2, vec![1.0]));
+        rhs.push(points[n]);
+
+        let control_points = BandedMatrix::from_rows(&rows)?.solve(&rhs)?;
+        Ok(curve_from(control_points, knots, degree))
+    }
+
+    // Least-squares fit of a curve with num_control_points control points to [x0, y0, x1, y1, ...]
+    // The curve starts and ends exactly at the first and last point, parameters follow the chord length
+    // Fails if there are fewer than degree + 1 control points or more control points than points
+    pub fn approximate(
+        points: Vec<f64>,
+        degree: usize,
+        num_control_points: usize,
+    ) -> Result<NurbsCurve, NurbsError> {
+        let data = pairs(&points)?;
+        check_fit_degree(degree, num_control_points, degree + 1)?;
+        if num_control_points > data.len() {
+            return Err(NurbsError::InvalidArgument(
+                "there must be at least as many points as control points",
+            ));
+        }
+
+        // As many control points as data points is plain interpolation
+        if num_control_points == data.len() {
+            return NurbsCurve::interpolate(points, degree, Parameterization::ChordLength);
+        }
+
+        let params = parameters(&data, Parameterization::ChordLength);
+        let knots = approximation_knots(&params, degree, num_control_points);
+        least_squares(&data, &params, degree, knots)
+    }
+
+    // Least-squares fit that adds knots until every point is within `tolerance` of the curve
+    // Starts from a single Bezier segment and splits the spans whose points deviate too much.
+    // The achieved error is reported even if the tolerance could not be met
+    pub fn fit_to_tolerance(
+        points: Vec<f64>,
+        degree: usize,
+        tolerance: f64,
+    ) -> Result<CurveFit, NurbsError> {
+        let data = pairs(&points)?;
+        check_fit_degree(degree, data.len(), degree + 1)?;
+        if tolerance.is_nan() {
+            return Err(NurbsError::InvalidArgument(
+                "the tolerance must be a number",
+            ));
+        }
+
+        let params = parameters(&data, Parameterization::ChordLength);
+        let mut knots = approximation_knots(&params, degree, degree + 1);
+        let mut best = fit_result(
+            least_squares(&data, &params, degree, knots.clone())?,
+            &data,
+            &params,
+        );
+
+        for _ in 0..MAX_FIT_ROUNDS {
+            if best.max_error <= tolerance {
+                break;
+            }
+
+            // Split each span containing a bad point at the mean parameter of its points
+            let errors = point_errors(&best.curve, &data, &params);
+            let mut new_knots = Vec::new();
+            for span in knots[degree..knots.len() - degree].windows(2) {
+                let inside = params
+                    .iter()
+                    .zip(&errors)
+                    .filter(|&(&u, _)| u > span[0] && u < span[1]);
+                let (count, sum, worst) = inside
+                    .fold((0, 0.0, 0.0_f64), |(count, sum, worst), (u, e)| {
+                        (count + 1, sum + u, worst.max(*e))
+                    });
+                if count > 0 && worst > tolerance {
+                    new_knots.push(sum / count as f64);
+                }
+            }
+
+            let num_control_points = knots.len() - degree - 1 + new_knots.len();
+            if new_knots.is_empty() || num_control_points > data.len() {
+                break;
+            }
+
+            let mut refined = knots.clone();
+            refined.extend(new_knots);
+            refined.sort_by(f64::total_cmp);
+            let curve = match least_squares(&data, &params, degree, refined.clone()) {
+                Ok(curve) => curve,
+                Err(_) => break,
+            };
+
+            knots = refined;
+            let fit = fit_result(curve, &data, &params);
+            if fit.max_error < best.max_error {
+                best = fit;
+            }
+        }
+
+        Ok(best)
+    }
+}
+
+// Fitting needs a positive degree and at least `required` points or control points
+fn check_fit_degree(degree: usize, num_points: usize, required: usize) -> Result<(), NurbsError> {
+    if num_points == 0 {
+        return Err(NurbsError::EmptyCurve);
+    }
+    if degree == 0 || num_points < required {
+        return Err(NurbsError::InvalidDegree { degree, num_points });
+    }
+    Ok(())
+}
+
+// Group a flat [x0, y0, x1, y1, ...] array into points
+pub(crate) fn pairs(values: &[f64]) -> Result<Vec<[f64; 2]>, NurbsError> {
+    if values.len() % 2 == 1 || values.iter().any(|v| !v.is_finite()) {
+        return Err(NurbsError::InvalidArgument(
+            "points must be a flat array of finite x, y pairs",
+        ));
+    }
+    Ok(values.chunks_exact(2).map(|p| [p[0], p[1]]).collect())
+}
+
+// Parameters in [0, 1] for each point, starting at 0 and ending at 1
+pub(crate) fn parameters(points: &[[f64; 2]], parameterization: Parameterization) -> Vec<f64> {
+    let n = points.len().saturating_sub(1);
+    let uniform = || (0..=n).map(|k| k as f64 / n.max(1) as f64).collect();
+
+    let exponent = match parameterization {
+        Parameterization::Uniform => return uniform(),
+        Parameterization::ChordLength => 1.0,
+        Parameterization::Centripetal => 0.5,
+    };
+
+    let steps: Vec<f64> = points
+        .windows(2)
+        .map(|pair| {
+            let d = (pair[1][0] - pair[0][0]).hypot(pair[1][1] - pair[0][1]);
+            d.powf(exponent)
+        })
+        .collect();
+    let total: f64 = steps.iter().sum();
+    if total <= 0.0 {
+        return uniform();
+    }
+
+    let mut params = Vec::with_capacity(points.len());
+    let mut accumulated = 0.0;
+    params.push(0.0);
+    for step in &steps[..steps.len() - 1] {
+        accumulated += step;
+        params.push(accumulated / total);
+    }
+    params.push(1.0);
+    params
+}
+
+// Clamped knot vector on [0, 1] whose interior knots average `degree` consecutive parameters
+// `extra` adds control points, each shifting the averaging window one parameter back
+pub(crate) fn averaged_knots(params: &[f64], degree: usize, extra: usize) -> Vec<f64> {
+    let num_control_points = params.len() + 2 * extra;
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..num_control_points - degree {
+        let first = j - extra;
+        let sum: f64 = params[first..first + degree].iter().sum();
+        knots.push(sum / degree as f64);
+    }
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    knots
+}
+
+// Non-zero basis functions at u together with the index of the first one
+pub(crate) fn basis_row(
+    knots: &[f64],
+    degree: usize,
+    num_control_points: usize,
+    u: f64,
+) -> (usize, Vec<f64>) {
+    match core::find_span(knots, degree, num_control_points, u) {
+        Some(span) => (span - degree, core::basis_functions(knots, degree, span, u)),
+        None => (0, vec![0.0; degree + 1]),
+    }
+}
+
+// Clamped knot vector on [0, 1] spreading the interior knots so every span holds data parameters
+// (The NURBS Book, eq. 9.68 and 9.69)
+fn approximation_knots(params: &[f64], degree: usize, num_control_points: usize) -> Vec<f64> {
+    let d = params.len() as f64 / (num_control_points - degree) as f64;
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..num_control_points - degree {
+        let i = (j as f64 * d) as usize;
+        let alpha = j as f64 * d - i as f64;
+        knots.push((1.0 - alpha) * params[i - 1] + alpha * params[i]);
+    }
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    knots
+}
+
+// Fit the interior control points by least squares, keeping the end points on the data ends
+fn least_squares(
+    points: &[[f64; 2]],
+    params: &[f64],
+    degree: usize,
+    knots: Vec<f64>,
+) -> Result<NurbsCurve, NurbsError> {
+    let num_control_points = knots.len().saturating_sub(degree + 1);
+    if num_control_points < 2 || points.len() < num_control_points {
+        return Err(NurbsError::InvalidDegree {
+            degree,
+            num_points: num_control_points,
+        });
+    }
+    let n = num_control_points - 1;
+    let m = points.len() - 1;
+
+    let first = points[0];
+    let last = points[m];
+    let unknowns = n - 1;
+
+    // Normal equations for the interior control points, banded with half width degree
+    let mut band = vec![vec![0.0; 2 * degree + 1]; unknowns];
+    let mut rhs = vec![[0.0; 2]; unknowns];
+    for (point, &u) in points.iter().zip(params).take(m).skip(1) {
+        let (offset, basis) = basis_row(&knots, degree, num_control_points, u);
+
+        // Remove the contribution of the fixed end points
+        let mut residual = *point;
+        for (a, value) in basis.iter().enumerate() {
+            let end = match offset + a {
+                0 => first,
+                i if i == n => last,
+                _ => continue,
+            };
+            residual[0] -= value * end[0];
+            residual[1] -= value * end[1];
+        }
+
+        for (a, value_a) in basis.iter().enumerate() {
+            let i = offset + a;
+            if i == 0 || i == n {
+                continue;
+            }
+            rhs[i - 1][0] += value_a * residual[0];
+            rhs[i - 1][1] += value_a * residual[1];
+            for (b, value_b) in basis.iter().enumerate() {
+                let j = offset + b;
+                if j != 0 && j != n {
+                    band[i - 1][j + degree - i] += value_a * value_b;
+                }
+            }
+        }
+    }
+
+    let mut control_points = vec![first];
+    if unknowns > 0 {
+        let rows: Vec<(usize, Vec<f64>)> = band
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let start = i.saturating_sub(degree);
+                let end = (i + degree).min(unknowns - 1);
+                (start, row[start + degree - i..=end + degree - i].to_vec())
+            })
+            .collect();
+        control_points.extend(BandedMatrix::from_rows(&rows)?.solve(&rhs)?);
+    }
+    control_points.push(last);
+
+    Ok(curve_from(control_points, knots, degree))
+}
+
+// Distance from each data point to the curve point at its parameter
+fn point_errors(curve: &NurbsCurve, points: &[[f64; 2]], params: &[f64]) -> Vec<f64> {
+    points
+        .iter()
+        .zip(params)
+        .map(|(point, &u)| match curve.evaluate(u) {
+            Ok(c) => (c.x - point[0]).hypot(c.y - point[1]),
+            Err(_) => f64::INFINITY,
+        })
+        .collect()
+}
+
+fn fit_result(curve: NurbsCurve, points: &[[f64; 2]], params: &[f64]) -> CurveFit {
+    let max_error = point_errors(&curve, points, params)
+        .into_iter()
+        .fold(0.0, f64::max);
+    CurveFit { curve, max_error }
+}
+
+// Clamped curve with unit weights
+fn curve_from(points: Vec<[f64; 2]>, knots: Vec<f64>, degree: usize) -> NurbsCurve {
+    let control_points = points
+        .iter()
+        .map(|p| ControlPoint::new(p[0], p[1], 1.0))
+        .collect();
+    NurbsCurve::from_parts(control_points, knots, degree)
+}
+
+// Square banded matrix, stored by row with room for the fill-in of partial pivoting
+pub(crate) struct BandedMatrix {
+    size: usize,
+    lower: usize,
+    width: usize,
+    values: Vec<f64>,
+}
+
+impl BandedMatrix {
+    // Build from sparse rows given as (first column, values)
+    pub(crate) fn from_rows(rows: &[(usize, Vec<f64>)]) -> Result<BandedMatrix, NurbsError> {
+        let size = rows.len();
+        let mut lower = 0;
+        let mut upper = 0;
+        for (r, (first, values)) in rows.iter().enumerate() {
+            if values.is_empty() || first + values.len() > size {
+                return Err(NurbsError::SingularSystem);
+            }
+            lower = lower.max(r.saturating_sub(*first));
+            upper = upper.max((first + values.len() - 1).saturating_sub(r));
+        }
+
+        let mut matrix = BandedMatrix {
+            size,
+            lower,
+            width: 2 * lower + upper + 1,
+            values: vec![0.0; size * (2 * lower + upper + 1)],
+        };
+        for (r, (first, values)) in rows.iter().enumerate() {
+            for (c, &value) in values.iter().enumerate() {
+                *matrix.at(r, first + c) = value;
+            }
+        }
+        Ok(matrix)
+    }
+
+    // Entry (r, c), which must lie inside the stored band of row r
+    fn at(&mut self, r: usize, c: usize) -> &mut f64 {
+        &mut self.values[r * self.width + c + self.lower - r]
+    }
+
+    // Last column stored for row r
+    fn last_column(&self, r: usize) -> usize {
+        (r + self.width - self.lower - 1).min(self.size - 1)
+    }
+
+    // Solve A x = b by Gaussian elimination with partial pivoting, one column per coordinate
+    // Fails if the matrix is singular
+    pub(crate) fn solve<const D: usize>(
+        mut self,
+        rhs: &[[f64; D]],
+    ) -> Result<Vec<[f64; D]>, NurbsError> {
+        if rhs.len() != self.size {
+            return Err(NurbsError::SingularSystem);
+        }
+        let mut b = rhs.to_vec();
+
+        for r in 0..self.size {
+            let last_row = (r + self.lower).min(self.size - 1);
+            let last_column = self.last_column(r);
+
+            // Choose the largest pivot among the rows that reach into this column
+            let pivot = (r..=last_row)
+                .max_by(|&a, &b| self.at(a, r).abs().total_cmp(&self.at(b, r).abs()))
+                .ok_or(NurbsError::SingularSystem)?;
+            if self.at(pivot, r).abs() < MIN_PIVOT {
+                return Err(NurbsError::SingularSystem);
+            }
+            if pivot != r {
+                for c in r..=last_column {
+                    let value = *self.at(r, c);
+                    *self.at(r, c) = *self.at(pivot, c);
+                    *self.at(pivot, c) = value;
+                }
+                b.swap(r, pivot);
+            }
+
+            let diagonal = *self.at(r, r);
+            for q in r + 1..=last_row {
+                let factor = *self.at(q, r) / diagonal;
+                if factor == 0.0 {
+                    continue;
+                }
+                for c in r..=last_column {
+                    let value = *self.at(r, c);
+                    *self.at(q, c) -= factor * value;
+                }
+                let source = b[r];
+                for (target, value) in b[q].iter_mut().zip(source) {
+                    *target -= factor * value;
+                }
+            }
+        }
+
+        // Back substitution
+        let mut x = vec![[0.0; D]; self.size];
+        for r in (0..self.size).rev() {
+            let mut value = b[r];
+            let last_column = self.last_column(r);
+            for (c, known) in x.iter().enumerate().take(last_column + 1).skip(r + 1) {
+                let coefficient = *self.at(r, c);
+                for (v, known) in value.iter_mut().zip(known) {
+                    *v -= coefficient * known;
+                }
+            }
+            let diagonal = *self.at(r, r);
+            x[r] = value.map(|v| v / diagonal);
+        }
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pairs, parameters, BandedMatrix, Parameterization};
+    use crate::{NurbsCurve, NurbsError};
+    use std::f64::consts::PI;
+
+    const POINTS: [f64; 14] = [
+        0.0, 0.0, 1.0, 2.0, 3.0, 3.0, 4.0, 1.0, 6.0, 0.5, 9.0, 2.0, 10.0, 4.0,
+    ];
+
+    #[test]
+    fn test_banded_solver() {
+        // Tridiagonal system with a zero on the diagonal that requires pivoting
+        let rows = vec![
+            (0, vec![0.0, 2.0]),
+            (0, vec![1.0, 1.0, 1.0]),
+            (1, vec![3.0, 1.0, 2.0]),
+            (2, vec![1.0, 4.0]),
+        ];
+        let expected = [[1.0, -1.0], [2.0, 0.5], [3.0, 2.0], [-1.0, 1.0]];
+        let rhs = [[4.0, 1.0], [6.0, 1.5], [7.0, 5.5], [-1.0, 6.0]];
+
+        let x = BandedMatrix::from_rows(&rows).unwrap().solve(&rhs).unwrap();
+        for (value, expected) in x.iter().zip(expected) {
+            assert!((value[0] - expected[0]).abs() < 1e-12);
+            assert!((value[1] - expected[1]).abs() < 1e-12);
+        }
+
+        // Singular systems are rejected
+        let singular = vec![(0, vec![1.0, 1.0]), (0, vec![2.0, 2.0])];
+        let rhs = [[1.0], [2.0]];
+        assert!(BandedMatrix::from_rows(&singular)
+            .unwrap()
+            .solve(&rhs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_interpolation_passes_through_points() {
+        let points = pairs(&POINTS).unwrap();
+
+        for parameterization in [
+            Parameterization::Uniform,
+            Parameterization::ChordLength,
+            Parameterization::Centripetal,
+        ] {
+            for degree in 1..=4 {
+                let curve =
+                    NurbsCurve::interpolate(POINTS.to_vec(), degree, parameterization).unwrap();
+                assert_eq!(curve.get_degree(), degree);
+                assert_eq!(curve.num_control_points(), points.len());
+
+                for (point, u) in points.iter().zip(parameters(&points, parameterization)) {
+                    let c = curve.evaluate(u).unwrap();
+                    assert!((c.x() - point[0]).abs() < 1e-10);
+                    assert!((c.y() - point[1]).abs() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parameterizations() {
+        let points = [[0.0, 0.0], [1.0, 0.0], [5.0, 0.0]];
+        assert_eq!(
+            parameters(&points, Parameterization::Uniform),
+            vec![0.0, 0.5, 1.0]
+        );
+        assert_eq!(
+            parameters(&points, Parameterization::ChordLength),
+            vec![0.0, 0.2, 1.0]
+        );
+        assert_eq!(
+            parameters(&points, Parameterization::Centripetal),
+            vec![0.0, 1.0 / 3.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_interpolation_with_end_tangents() {
+        let start = vec![0.0, 20.0];
+        let end = vec![15.0, 0.0];
+
+        for degree in 2..=4 {
+            let curve = NurbsCurve::interpolate_with_end_tangents(
+                POINTS.to_vec(),
+                degree,
+                Parameterization::ChordLength,
+                start.clone(),
+                end.clone(),
+            )
+            .unwrap();
+            assert_eq!(curve.num_control_points(), POINTS.len() / 2 + 2);
+
+            let points = pairs(&POINTS).unwrap();
+            for (point, u) in points
+                .iter()
+                .zip(parameters(&points, Parameterization::ChordLength))
+            {
+                let c = curve.evaluate(u).unwrap();
+                assert!((c.x() - point[0]).abs() < 1e-10);
+                assert!((c.y() - point[1]).abs() < 1e-10);
+            }
+
+            let d0 = curve.derivatives(0.0, 1).unwrap();
+            let d1 = curve.derivatives(1.0, 1).unwrap();
+            assert!((d0[2] - start[0]).abs() < 1e-9 && (d0[3] - start[1]).abs() < 1e-9);
+            assert!((d1[2] - end[0]).abs() < 1e-9 && (d1[3] - end[1]).abs() < 1e-9);
+        }
+
+        // A polyline has no spare control points to carry the tangents
+        let polyline = NurbsCurve::interpolate_with_end_tangents(
+            POINTS.to_vec(),
+            1,
+            Parameterization::ChordLength,
+            start,
+            end,
+        );
+        assert!(matches!(
+            polyline,
+            Err(NurbsError::InvalidDegree { degree: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_interpolation_input() {
+        let uniform = Parameterization::Uniform;
+        assert!(NurbsCurve::interpolate(vec![0.0, 0.0, 1.0, 1.0], 2, uniform).is_err());
+        assert!(NurbsCurve::interpolate(vec![0.0, 0.0, 1.0], 1, uniform).is_err());
+        assert!(NurbsCurve::interpolate(POINTS.to_vec(), 0, uniform).is_err());
+
+        // Repeated points get the same chord-length parameter and cannot be interpolated
+        let repeated = vec![0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 0.0];
+        assert!(NurbsCurve::interpolate(repeated, 2, Parameterization::ChordLength).is_err());
+
+        assert!(NurbsCurve::interpolate_with_end_tangents(
+            POINTS.to_vec(),
+            3,
+            uniform,
+            vec![1.0],
+            vec![1.0, 0.0]
+        )
+        .is_err());
+    }
+
+    // Samples of a wave with a small deterministic wobble, like a digitized pen stroke
+    fn stroke(count: usize) -> Vec<f64> {
+        (0..count)
+            .flat_map(|k| {
+                let t = k as f64 / (count - 1) as f64;
+                let wobble = 0.002 * ((k * 7919 % 13) as f64 - 6.0);
+                [10.0 * t, (2.0 * PI * t).sin() + wobble]
+            })
+            .collect()
+    }
+
+    fn max_error(curve: &NurbsCurve, points: &[f64]) -> f64 {
+        let data = pairs(points).unwrap();
+        let params = parameters(&data, Parameterization::ChordLength);
+        data.iter()
+            .zip(params)
+            .map(|(point, u)| {
+                let c = curve.evaluate(u).unwrap();
+                (c.x() - point[0]).hypot(c.y() - point[1])
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_approximate_keeps_end_points() {
+        let points = stroke(200);
+        let curve = NurbsCurve::approximate(points.clone(), 3, 12).unwrap();
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.num_control_points(), 12);
+
+        let start = curve.evaluate(0.0).unwrap();
+        let end = curve.evaluate(1.0).unwrap();
+        assert_eq!((start.x(), start.y()), (points[0], points[1]));
+        assert_eq!((end.x(), end.y()), (points[398], points[399]));
+
+        // More control points follow the data more closely
+        let coarse = NurbsCurve::approximate(points.clone(), 3, 6).unwrap();
+        assert!(max_error(&curve, &points) < max_error(&coarse, &points));
+        assert!(max_error(&curve, &points) < 0.05);
+    }
+
+    #[test]
+    fn test_approximate_reproduces_a_line() {
+        let points: Vec<f64> = (0..50)
+            .flat_map(|k| {
+                let t = (k as f64 / 49.0).powi(2);
+                [1.0 + 4.0 * t, 2.0 - 3.0 * t]
+            })
+            .collect();
+        let curve = NurbsCurve::approximate(points.clone(), 2, 5).unwrap();
+        assert!(max_error(&curve, &points) < 1e-10);
+    }
+
+    #[test]
+    fn test_approximate_invalid_input() {
+        let points = stroke(10);
+        assert!(NurbsCurve::approximate(points.clone(), 3, 3).is_err());
+        assert!(NurbsCurve::approximate(points.clone(), 3, 11).is_err());
+        assert!(NurbsCurve::approximate(points.clone(), 0, 4).is_err());
+
+        // As many control points as points interpolates
+        let curve = NurbsCurve::approximate(points.clone(), 3, 10).unwrap();
+        assert!(max_error(&curve, &points) < 1e-10);
+    }
+
+    #[test]
+    fn test_fit_to_tolerance() {
+        let points = stroke(300);
+
+        for tolerance in [0.1, 0.02] {
+            let fit = NurbsCurve::fit_to_tolerance(points.clone(), 3, tolerance).unwrap();
+            assert!(fit.max_error() <= tolerance);
+            assert!((fit.max_error() - max_error(&fit.curve(), &points)).abs() < 1e-12);
+            assert!(fit.curve().num_control_points() < 40);
+        }
+
+        // Tolerances below the noise cannot be met, but the best error is still reported
+        let fit = NurbsCurve::fit_to_tolerance(stroke(30), 3, 1e-9).unwrap();
+        assert!(fit.max_error() > 1e-9);
+        assert!(fit.max_error().is_finite());
+        assert!(fit.curve().num_control_points() <= 30);
+    }
+}
@@ -0,0 +1,188 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core;
+use crate::fitting::{averaged_knots, parameters};
+use crate::{NurbsCurve, Parameterization};
+
+// How the knot vector is generated when control points are added
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KnotStrategy {
+    // Evenly spaced knots with end knots repeated so the curve starts and ends at its end points
+    UniformClamped,
+    // Evenly spaced knots without repeated ends, the curve does not reach its end points
+    UniformUnclamped,
+    // Clamped knots averaged from the distances between control points
+    ChordLength,
+    // Clamped knots averaged from the square roots of those distances
+    Centripetal,
+    // Knots supplied with set_knots, never regenerated
+    Locked,
+}
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Choose how knots are generated and regenerate them for the current control points
+    // Locked keeps the current knots. Periodic curves keep their uniform unclamped knots
+    // and apply the strategy once periodic mode is turned off
+    pub fn set_knot_strategy(&mut self, strategy: KnotStrategy) {
+        self.knot_strategy = strategy;
+        if !self.periodic {
+            self.update_knots();
+        }
+    }
+
+    pub fn get_knot_strategy(&self) -> KnotStrategy {
+        self.knot_strategy
+    }
+}
+
+impl NurbsCurve {
+    // Go back to uniform clamped generation when locked knots are replaced wholesale
+    pub(crate) fn unlock_knots(&mut self) {
+        if self.knot_strategy == KnotStrategy::Locked {
+            self.knot_strategy = KnotStrategy::UniformClamped;
+        }
+    }
+
+    // Knot vector for the current control points following the knot strategy,
+    // None if the strategy keeps the current knots
+    pub(crate) fn generated_knots(&self) -> Option<Vec<f64>> {
+        let count = self.control_points.len();
+        let p = self.effective_degree();
+        let parameterization = match self.knot_strategy {
+            KnotStrategy::UniformClamped => return Some(core::uniform_clamped_knots(count, p)),
+            KnotStrategy::UniformUnclamped => return Some(core::uniform_unclamped_knots(count, p)),
+            KnotStrategy::ChordLength => Parameterization::ChordLength,
+            KnotStrategy::Centripetal => Parameterization::Centripetal,
+            KnotStrategy::Locked => return None,
+        };
+
+        // A single point or degree 0 has nothing to average over, fall back to the uniform layout
+        if p == 0 {
+            return Some(core::uniform_clamped_knots(count, p));
+        }
+
+        let points: Vec<[f64; 2]> = self.control_points.iter().map(|cp| [cp.x, cp.y]).collect();
+        Some(averaged_knots(&parameters(&points, parameterization), p, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KnotStrategy;
+    use crate::{ControlPoint, NurbsCurve, Parameterization};
+
+    fn curve(degree: usize, xs: &[f64]) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(degree);
+        for &x in xs {
+            curve.add_control_point(ControlPoint::new(x, 0.0, 1.0));
+        }
+        curve
+    }
+
+    #[test]
+    fn test_default_strategy_is_uniform_clamped() {
+        let curve = curve(2, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::UniformClamped);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_chord_length_and_centripetal_knots() {
+        let mut curve = curve(1, &[0.0, 1.0, 5.0]);
+        curve.set_knot_strategy(KnotStrategy::ChordLength);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.2, 1.0, 1.0]);
+
+        // Added points keep following the strategy
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.1, 0.5, 1.0, 1.0]);
+
+        curve.set_knot_strategy(KnotStrategy::Centripetal);
+        let knots = curve.get_knots();
+        let total = 1.0 + 2.0 + 5.0_f64.sqrt();
+        assert!((knots[2] - 1.0 / total).abs() < 1e-12);
+        assert!((knots[3] - 3.0 / total).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_uniform_unclamped_knots() {
+        let mut curve = curve(2, &[0.0, 1.0, 2.0, 3.0, 4.0]);
+        curve.set_knot_strategy(KnotStrategy::UniformUnclamped);
+
+        let knots = curve.get_knots();
+        assert_eq!(knots.len(), 8);
+        assert_eq!(curve.domain().unwrap().to_vec(), vec![0.0, 1.0]);
+
+        // Without clamping the curve starts between the first two control points
+        let start = curve.evaluate(0.0).unwrap();
+        assert!((start.x() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_set_knots_locks_the_knot_vector() {
+        let mut curve = curve(2, &[0.0, 1.0, 2.0]);
+        curve.set_knots(vec![0.0, 0.0, 0.0, 2.0, 2.0, 2.0]).unwrap();
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::Locked);
+
+        // Adding a point leaves the supplied knots alone until new ones are set
+        curve.add_control_point(ControlPoint::new(3.0, 0.0, 1.0));
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 2.0, 2.0, 2.0]);
+        curve
+            .set_knots(vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0])
+            .unwrap();
+        assert_eq!(curve.domain().unwrap().to_vec(), vec![0.0, 2.0]);
+
+        // Choosing a generated strategy again replaces them
+        curve.set_knot_strategy(KnotStrategy::UniformClamped);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    }
+
+    // Adding a point and removing it again must give back the same shape
+    fn assert_shape_survives_added_point(mut curve: NurbsCurve) {
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::Locked);
+        let knots = curve.get_knots();
+        let before: Vec<(f64, f64)> = curve
+            .generate_points(32)
+            .unwrap()
+            .iter()
+            .map(|point| (point.x(), point.y()))
+            .collect();
+
+        curve.add_control_point(ControlPoint::new(5.0, 5.0, 1.0));
+        assert_eq!(curve.get_knots(), knots);
+
+        curve
+            .remove_control_point(curve.num_control_points() - 1)
+            .unwrap();
+        let after: Vec<(f64, f64)> = curve
+            .generate_points(32)
+            .unwrap()
+            .iter()
+            .map(|point| (point.x(), point.y()))
+            .collect();
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn test_built_curves_keep_their_knots() {
+        let circle = NurbsCurve::circle(0.0, 0.0, 1.0).unwrap();
+        assert_shape_survives_added_point(circle.clone());
+
+        let fitted = NurbsCurve::interpolate(
+            vec![0.0, 0.0, 1.0, 2.0, 3.0, 1.0, 4.0, 4.0, 6.0, 0.0],
+            3,
+            Parameterization::ChordLength,
+        )
+        .unwrap();
+        assert_shape_survives_added_point(fitted);
+
+        let (left, right) = circle.split_at(0.4).unwrap();
+        assert_shape_survives_added_point(left);
+        assert_shape_survives_added_point(right);
+
+        let mut refined = curve(2, &[0.0, 1.0, 2.0, 3.0]);
+        refined.insert_knot(0.3, 1).unwrap();
+        assert_shape_survives_added_point(refined);
+    }
+}
@@ -0,0 +1,709 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{KnotError, KnotStrategy, NurbsCurve, NurbsError};
+
+// Knot edits lock the knot vector, a generated strategy would undo them on the next added point
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Insert the knot u up to `times` times without changing the shape of the curve (Boehm's algorithm)
+    // Returns the number of insertions performed, which is limited so the multiplicity never exceeds the degree
+    // Fails if u is not strictly inside the domain
+    pub fn insert_knot(&mut self, u: f64, times: usize) -> Result<usize, NurbsError> {
+        let p = self.effective_degree();
+        self.check_interior(u)?;
+
+        let s = self.knot_multiplicity(u);
+        if times == 0 || s >= p {
+            return Ok(0);
+        }
+        let r = times.min(p - s);
+
+        let k = self.find_span(u).ok_or(NurbsError::EmptyCurve)?;
+
+        let old_points = self.homogeneous_points();
+        let old_knots = &self.knots;
+
+        // New knot vector with u repeated r times after span k
+        let mut knots = Vec::with_capacity(old_knots.len() + r);
+        knots.extend_from_slice(&old_knots[..=k]);
+        knots.extend(std::iter::repeat_n(u, r));
+        knots.extend_from_slice(&old_knots[k + 1..]);
+
+        // Control points outside the affected range are unchanged
+        let mut points = vec![[0.0; 3]; old_points.len() + r];
+        points[..=k - p].copy_from_slice(&old_points[..=k - p]);
+        points[k - s + r..].copy_from_slice(&old_points[k - s..]);
+
+        // Blend the affected points once per inserted knot
+        let mut temp: Vec<[f64; 3]> = old_points[k - p..=k - s].to_vec();
+        let mut last = k - p;
+        for j in 1..=r {
+            last = k - p + j;
+            for i in 0..=p - j - s {
+                let denom = old_knots[i + k + 1] - old_knots[last + i];
+                let alpha = if denom.abs() < 1e-10 {
+                    0.0
+                } else {
+                    (u - old_knots[last + i]) / denom
+                };
+                let next = temp[i + 1];
+                for (value, next) in temp[i].iter_mut().zip(next) {
+                    *value = alpha * next + (1.0 - alpha) * *value;
+                }
+            }
+            points[last] = temp[0];
+            points[k + r - j - s] = temp[p - j - s];
+        }
+
+        // Load the remaining blended points
+        if last + 1 < k - s {
+            points[last + 1..k - s].copy_from_slice(&temp[1..k - s - last]);
+        }
+
+        // A curve with too few points for its degree keeps the lower degree it was evaluated with
+        self.degree = p;
+        self.knots = knots;
+        self.knot_strategy = KnotStrategy::Locked;
+        self.set_homogeneous_points(&points);
+
+        Ok(r)
+    }
+
+    // Insert many knots at once without changing the shape of the curve
+    // Values outside the domain interior, and copies that would raise a multiplicity above the degree, are skipped
+    // Returns the number of knots actually inserted
+    pub fn refine_knots(&mut self, new_knots: &[f64]) -> Result<usize, NurbsError> {
+        let p = self.effective_degree();
+        let (start, end) = self.checked_range()?;
+
+        let mut sorted: Vec<f64> = new_knots
+            .iter()
+            .copied()
+            .filter(|&u| u.is_finite() && u > start && u < end)
+            .collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // Keep each value only while its total multiplicity stays within the degree
+        let mut accepted: Vec<f64> = Vec::with_capacity(sorted.len());
+        for u in sorted {
+            let existing = self.knot_multiplicity(u);
+            let added = accepted.iter().rev().take_while(|&&x| x == u).count();
+            if existing + added < p {
+                accepted.push(u);
+            }
+        }
+
+        if accepted.is_empty() {
+            return Ok(0);
+        }
+
+        let (points, knots) = self.refined(&accepted);
+        self.degree = p;
+        self.knots = knots;
+        self.knot_strategy = KnotStrategy::Locked;
+        self.set_homogeneous_points(&points);
+
+        Ok(accepted.len())
+    }
+
+    // Split the curve into rational Bezier segments, one per non-empty knot span
+    // Each segment is a clamped curve of the same degree on the parameter range [0, 1]
+    pub fn decompose_to_bezier(&self) -> Result<Vec<NurbsCurve>, NurbsError> {
+        let p = self.effective_degree();
+        self.checked_range()?;
+        let spans = self.knot_spans();
+        if spans.is_empty() {
+            return Err(KnotError::EmptyDomain.into());
+        }
+
+        // Raise every breakpoint, including both ends of the domain, to multiplicity p
+        let mut breakpoints = vec![spans[0].0];
+        breakpoints.extend(spans.iter().map(|&(_, b)| b));
+        let mut extra = Vec::new();
+        for &u in &breakpoints {
+            let s = self.knot_multiplicity(u);
+            extra.extend(std::iter::repeat_n(u, p.saturating_sub(s)));
+        }
+
+        let (points, refined_knots) = if extra.is_empty() {
+            (self.homogeneous_points(), self.knots.clone())
+        } else {
+            self.refined(&extra)
+        };
+        let mut refined = NurbsCurve::from_parts(Vec::new(), refined_knots, p);
+        refined.set_homogeneous_points(&points);
+
+        let mut knots = vec![0.0; p + 1];
+        knots.extend(std::iter::repeat_n(1.0, p + 1));
+
+        // The segment over [a, b] is controlled by the p + 1 points ending at the last knot equal to a
+        Ok(spans
+            .iter()
+            .map(|&(a, _)| {
+                let k = refined.knots.partition_point(|&t| t <= a) - 1;
+                NurbsCurve::from_parts(refined.control_points[k - p..=k].to_vec(), knots.clone(), p)
+            })
+            .collect())
+    }
+
+    // Remove the interior knot u up to `times` times (Tiller's algorithm)
+    // A removal only succeeds when the curve moves by at most `tolerance`, so with a tolerance of 0
+    // only knots that add no shape information are removed
+    // Returns the number of removals that succeeded, fails if u is not strictly inside the domain
+    pub fn remove_knot(
+        &mut self,
+        u: f64,
+        times: usize,
+        tolerance: f64,
+    ) -> Result<usize, NurbsError> {
+        let p = self.effective_degree();
+        self.check_interior(u)?;
+
+        let s = self.knot_multiplicity(u);
+        if times == 0 || s == 0 {
+            return Ok(0);
+        }
+
+        // r is the index of the last occurrence of u
+        let r = self.knots.partition_point(|&knot| knot <= u) - 1;
+
+        // A degree 0 curve is a step function with single interior knots, the step at u only
+        // goes away when the points on either side are within the tolerance
+        if p == 0 {
+            let (before, after) = (&self.control_points[r - 1], &self.control_points[r]);
+            if (before.x - after.x).hypot(before.y - after.y) > tolerance.max(0.0) {
+                return Ok(0);
+            }
+            self.knots.remove(r);
+            self.control_points.remove(r);
+            self.knot_strategy = KnotStrategy::Locked;
+            return Ok(1);
+        }
+
+        let tolerance = self.homogeneous_tolerance(tolerance.max(0.0));
+        let knots = &self.knots;
+        let mut points = self.homogeneous_points();
+        let order = p + 1;
+        let first_out = (2 * r - s - p) / 2;
+        let mut first = r - p;
+        let mut last = r - s;
+        let mut temp = vec![[0.0; 3]; 2 * p + 1];
+
+        let mut removed = 0;
+        while removed < times.min(s) {
+            let t = removed;
+            let off = first - 1;
+            temp[0] = points[off];
+            temp[last + 1 - off] = points[last + 1];
+
+            let mut i = first;
+            let mut j = last;
+            let mut ii = 1;
+            let mut jj = last - off;
+
+            // Solve for the new control points from both ends towards the middle
+            while j > i + t {
+                let alpha_i = (u - knots[i]) / (knots[i + order + t] - knots[i]);
+                let alpha_j = (u - knots[j - t]) / (knots[j + order] - knots[j - t]);
+                temp[ii] = combine(
+                    points[i],
+                    temp[ii - 1],
+                    1.0 / alpha_i,
+                    -(1.0 - alpha_i) / alpha_i,
+                );
+                temp[jj] = combine(
+                    points[j],
+                    temp[jj + 1],
+                    1.0 / (1.0 - alpha_j),
+                    -alpha_j / (1.0 - alpha_j),
+                );
+                i += 1;
+                ii += 1;
+                j -= 1;
+                jj -= 1;
+            }
+
+            // The two solutions must meet within the tolerance
+            let removable = if j < i + t {
+                distance(temp[ii - 1], temp[jj + 1]) <= tolerance
+            } else {
+                let alpha_i = (u - knots[i]) / (knots[i + order + t] - knots[i]);
+                let blended = combine(temp[ii + t + 1], temp[ii - 1], alpha_i, 1.0 - alpha_i);
+                distance(points[i], blended) <= tolerance
+            };
+
+            if !removable {
+                break;
+            }
+
+            let mut i = first;
+            let mut j = last;
+            while j > i + t {
+                points[i] = temp[i - off];
+                points[j] = temp[j - off];
+                i += 1;
+                j -= 1;
+            }
+
+            removed += 1;
+            first -= 1;
+            last += 1;
+        }
+
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // Shift the knots and control points to close the gaps
+        let mut knots = self.knots.clone();
+        knots.drain(r + 1 - removed..=r);
+
+        let mut j = first_out;
+        let mut i = j;
+        for k in 1..removed {
+            if k % 2 == 1 {
+                i += 1;
+            } else {
+                j -= 1;
+            }
+        }
+        points.drain(j..=i);
+
+        self.degree = p;
+        self.knots = knots;
+        self.knot_strategy = KnotStrategy::Locked;
+        self.set_homogeneous_points(&points);
+
+        Ok(removed)
+    }
+
+    // Try to remove every interior knot while keeping the curve within `tolerance` of its current shape
+    // Returns the total number of knots removed
+    pub fn simplify(&mut self, tolerance: f64) -> Result<usize, NurbsError> {
+        self.checked_range()?;
+
+        let mut interior: Vec<f64> = self.knot_spans().iter().skip(1).map(|&(a, _)| a).collect();
+        interior.dedup();
+        let limits: Vec<(f64, usize)> = interior
+            .into_iter()
+            .map(|u| (u, self.knot_multiplicity(u)))
+            .collect();
+
+        let original = self.clone();
+        Ok(self.remove_knots_within(&original, &limits, tolerance))
+    }
+}
+
+// Number of parameters per span used to check the deviation while removing knots
+const DEVIATION_SAMPLES_PER_SPAN: usize = 16;
+
+// a * s + b * t for homogeneous points
+fn combine(a: [f64; 3], b: [f64; 3], s: f64, t: f64) -> [f64; 3] {
+    [
+        a[0] * s + b[0] * t,
+        a[1] * s + b[1] * t,
+        a[2] * s + b[2] * t,
+    ]
+}
+
+fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+impl NurbsCurve {
+    // Check that the curve can be evaluated and u lies strictly inside its domain
+    fn check_interior(&self, u: f64) -> Result<(), NurbsError> {
+        let (start, end) = self.checked_range()?;
+        if u.is_finite() && u > start && u < end {
+            Ok(())
+        } else {
+            Err(NurbsError::ParameterOutOfDomain { u, start, end })
+        }
+    }
+
+    // Convert a geometric tolerance into a bound on homogeneous control point changes
+    fn homogeneous_tolerance(&self, tolerance: f64) -> f64 {
+        let min_weight = self
+            .control_points
+            .iter()
+            .map(|cp| cp.weight.abs())
+            .fold(f64::INFINITY, f64::min);
+        let max_norm = self
+            .control_points
+            .iter()
+            .map(|cp| cp.x.hypot(cp.y))
+            .fold(0.0, f64::max);
+        tolerance * min_weight / (1.0 + max_norm)
+    }
+
+    // Parameters spread evenly over every knot span, for comparing curves
+    pub(crate) fn deviation_samples(&self) -> Vec<f64> {
+        let spans = self.knot_spans();
+        spans
+            .iter()
+            .flat_map(|&(a, b)| {
+                (0..DEVIATION_SAMPLES_PER_SPAN)
+                    .map(move |i| a + (b - a) * i as f64 / DEVIATION_SAMPLES_PER_SPAN as f64)
+            })
+            .chain(spans.last().map(|&(_, b)| b))
+            .collect()
+    }
+
+    // Largest distance between this curve and another at the given parameters
+    pub(crate) fn max_deviation(&self, other: &NurbsCurve, parameters: &[f64]) -> f64 {
+        parameters
+            .iter()
+            .map(|&u| match (self.evaluate(u), other.evaluate(u)) {
+                (Ok(a), Ok(b)) => (a.x - b.x).hypot(a.y - b.y),
+                _ => f64::INFINITY,
+            })
+            .fold(0.0, f64::max)
+    }
+
+    // Remove each listed knot up to its given number of times, one removal at a time, as long as
+    // the curve stays within `tolerance` of `reference` at a set of sample parameters
+    // Returns the total number of knots removed
+    pub(crate) fn remove_knots_within(
+        &mut self,
+        reference: &NurbsCurve,
+        limits: &[(f64, usize)],
+        tolerance: f64,
+    ) -> usize {
+        let samples = reference.deviation_samples();
+        self.remove_knots_while(limits, tolerance, |candidate| {
+            candidate.max_deviation(reference, &samples) <= tolerance
+        })
+    }
+
+    // Remove each listed knot up to its given number of times, one removal at a time, keeping
+    // only removals that pass Tiller's `tolerance` check and that `accept` approves
+    // Returns the total number of knots removed
+    pub(crate) fn remove_knots_while(
+        &mut self,
+        limits: &[(f64, usize)],
+        tolerance: f64,
+        accept: impl Fn(&NurbsCurve) -> bool,
+    ) -> usize {
+        // Working from the end keeps earlier knot values valid while the vector shrinks
+        let mut total = 0;
+        for &(u, times) in limits.iter().rev() {
+            for _ in 0..times {
+                let mut candidate = self.clone();
+                if candidate.remove_knot(u, 1, tolerance) != Ok(1) || !accept(&candidate) {
+                    break;
+                }
+                *self = candidate;
+                total += 1;
+            }
+        }
+
+        total
+    }
+
+    // Refine the knot vector with the sorted values in `x` and return the new homogeneous points and knots
+    fn refined(&self, x: &[f64]) -> (Vec<[f64; 3]>, Vec<f64>) {
+        let p = self.effective_degree();
+        let n = self.control_points.len() - 1;
+        let m = n + p + 1;
+        let r = x.len() - 1;
+        let old_points = self.homogeneous_points();
+        let old_knots = &self.knots;
+
+        let a = self.find_span(x[0]).unwrap_or(p);
+        let b = self.find_span(x[r]).unwrap_or(n) + 1;
+
+        let mut points = vec![[0.0; 3]; n + r + 2];
+        let mut knots = vec![0.0; m + r + 2];
+
+        // Control points and knots outside the refined range are unchanged
+        points[..=a - p].copy_from_slice(&old_points[..=a - p]);
+        points[b + r..].copy_from_slice(&old_points[b - 1..]);
+        knots[..=a].copy_from_slice(&old_knots[..=a]);
+        knots[b + p + r + 1..].copy_from_slice(&old_knots[b + p..]);
+
+        // Work backwards through the new knots, blending the affected points
+        let mut i = b + p - 1;
+        let mut k = b + p + r;
+        for &u in x.iter().rev() {
+            while u <= old_knots[i] && i > a {
+                points[k - p - 1] = old_points[i - p - 1];
+                knots[k] = old_knots[i];
+                k -= 1;
+                i -= 1;
+            }
+
+            points[k - p - 1] = points[k - p];
+            for l in 1..=p {
+                let index = k - p + l;
+                let alpha = knots[k + l] - u;
+                if alpha.abs() < 1e-14 {
+                    points[index - 1] = points[index];
+                } else {
+                    let alpha = alpha / (knots[k + l] - old_knots[i - p + l]);
+                    let next = points[index];
+                    for (value, next) in points[index - 1].iter_mut().zip(next) {
+                        *value = alpha * *value + (1.0 - alpha) * next;
+                    }
+                }
+            }
+
+            knots[k] = u;
+            k -= 1;
+        }
+
+        (points, knots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve};
+
+    fn weighted_cubic() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 5.0, 2.0));
+        curve.add_control_point(ControlPoint::new(5.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(8.0, 4.0, 1.5));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(12.0, 3.0, 0.8));
+        curve
+    }
+
+    fn assert_same_shape(a: &NurbsCurve, b: &NurbsCurve) {
+        for i in 0..=200 {
+            let u = i as f64 / 200.0;
+            let pa = a.evaluate(u).unwrap();
+            let pb = b.evaluate(u).unwrap();
+            assert!((pa.x() - pb.x()).abs() < 1e-12, "x differs at u = {}", u);
+            assert!((pa.y() - pb.y()).abs() < 1e-12, "y differs at u = {}", u);
+        }
+    }
+
+    #[test]
+    fn test_insert_new_knot() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        assert_eq!(curve.insert_knot(0.3, 1), Ok(1));
+        assert_eq!(curve.num_control_points(), 7);
+        assert_eq!(curve.get_knots().len(), 11);
+        assert_eq!(curve.knot_multiplicity(0.3), 1);
+        assert_same_shape(&original, &curve);
+    }
+
+    #[test]
+    fn test_insert_knot_multiple_times() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        assert_eq!(curve.insert_knot(0.6, 2), Ok(2));
+        assert_eq!(curve.knot_multiplicity(0.6), 2);
+        assert_same_shape(&original, &curve);
+
+        // An existing knot can be raised up to the degree but no further
+        let existing = curve.get_knots()[4];
+        assert_eq!(curve.insert_knot(existing, 5), Ok(2));
+        assert_eq!(curve.knot_multiplicity(existing), 3);
+        assert_eq!(curve.insert_knot(existing, 1), Ok(0));
+        assert_same_shape(&original, &curve);
+
+        // At full multiplicity the curve passes through a control point
+        let point = curve.evaluate(existing).unwrap();
+        let passes = (0..curve.num_control_points()).any(|i| {
+            let cp = curve.get_control_point(i).unwrap();
+            (cp.x() - point.x()).abs() < 1e-12 && (cp.y() - point.y()).abs() < 1e-12
+        });
+        assert!(passes);
+    }
+
+    #[test]
+    fn test_insert_knot_outside_domain() {
+        let mut curve = weighted_cubic();
+        assert!(curve.insert_knot(0.0, 1).is_err());
+        assert!(curve.insert_knot(1.0, 1).is_err());
+        assert!(curve.insert_knot(f64::NAN, 1).is_err());
+        assert_eq!(curve.num_control_points(), 6);
+    }
+
+    #[test]
+    fn test_refine_knots() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        // Unsorted input, duplicates beyond the degree and out-of-domain values
+        let inserted = curve
+            .refine_knots(&[0.7, 0.1, 0.5, 0.5, 0.5, 0.5, -1.0, 1.0, 0.25])
+            .unwrap();
+        assert_eq!(inserted, 6);
+        assert_eq!(curve.num_control_points(), 12);
+        assert_eq!(curve.knot_multiplicity(0.5), 3);
+
+        let knots = curve.get_knots();
+        assert!(knots.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_same_shape(&original, &curve);
+
+        assert_eq!(curve.refine_knots(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_decompose_to_bezier() {
+        let curve = weighted_cubic();
+        let segments = curve.decompose_to_bezier().unwrap();
+
+        // Interior knots at 1/3 and 2/3 give three segments
+        assert_eq!(segments.len(), 3);
+        let breakpoints = [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0];
+
+        for (i, segment) in segments.iter().enumerate() {
+            assert_eq!(segment.num_control_points(), 4);
+            assert_eq!(
+                segment.get_knots(),
+                vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]
+            );
+
+            for j in 0..=20 {
+                let t = j as f64 / 20.0;
+                let u = breakpoints[i] + t * (breakpoints[i + 1] - breakpoints[i]);
+                let expected = curve.evaluate(u).unwrap();
+                let actual = segment.evaluate(t).unwrap();
+                assert!((expected.x() - actual.x()).abs() < 1e-12);
+                assert!((expected.y() - actual.y()).abs() < 1e-12);
+            }
+        }
+
+        // Segments join end to end
+        for pair in segments.windows(2) {
+            let end = pair[0].get_control_point(3).unwrap();
+            let start = pair[1].get_control_point(0).unwrap();
+            assert_eq!(
+                (end.x(), end.y(), end.weight()),
+                (start.x(), start.y(), start.weight())
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompose_unclamped_curve() {
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(1.0, 2.0, 2.0));
+        curve.add_control_point(ControlPoint::new(3.0, 2.0, 1.0));
+        curve.add_control_point(ControlPoint::new(4.0, 0.0, 0.5));
+        curve
+            .set_knots(vec![0.0, 0.1, 0.2, 0.5, 0.8, 0.9, 1.0])
+            .unwrap();
+
+        // The domain [0.2, 0.8] has a single interior knot
+        let segments = curve.decompose_to_bezier().unwrap();
+        assert_eq!(segments.len(), 2);
+
+        let breakpoints = [0.2, 0.5, 0.8];
+        for (i, segment) in segments.iter().enumerate() {
+            for j in 0..=20 {
+                let t = j as f64 / 20.0;
+                let u = breakpoints[i] + t * (breakpoints[i + 1] - breakpoints[i]);
+                let expected = curve.evaluate(u).unwrap();
+                let actual = segment.evaluate(t).unwrap();
+                assert!((expected.x() - actual.x()).abs() < 1e-12);
+                assert!((expected.y() - actual.y()).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_remove_inserted_knot() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+        curve.insert_knot(0.45, 2).unwrap();
+        assert_eq!(curve.num_control_points(), 8);
+
+        // Knots that were only inserted carry no shape information
+        assert_eq!(curve.remove_knot(0.45, 2, 1e-9), Ok(2));
+        assert_eq!(curve.num_control_points(), 6);
+        assert_eq!(curve.get_knots(), original.get_knots());
+        assert_same_shape(&original, &curve);
+
+        for i in 0..6 {
+            let a = original.get_control_point(i).unwrap();
+            let b = curve.get_control_point(i).unwrap();
+            assert!((a.x() - b.x()).abs() < 1e-9);
+            assert!((a.y() - b.y()).abs() < 1e-9);
+            assert!((a.weight() - b.weight()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_remove_knot_respects_tolerance() {
+        let mut curve = weighted_cubic();
+        curve.insert_knot(0.45, 1).unwrap();
+
+        // Nudge one of the new control points so the knot carries a little shape information
+        let cp = curve.get_control_point(3).unwrap();
+        curve
+            .update_control_point(3, cp.x(), cp.y() + 0.01, cp.weight())
+            .unwrap();
+
+        assert_eq!(curve.remove_knot(0.45, 1, 1e-6), Ok(0));
+        assert_eq!(curve.num_control_points(), 7);
+
+        assert_eq!(curve.remove_knot(0.45, 1, 1.0), Ok(1));
+        assert_eq!(curve.num_control_points(), 6);
+
+        // Values that are not knots cannot be removed
+        assert_eq!(curve.remove_knot(0.123, 1, 100.0), Ok(0));
+    }
+
+    #[test]
+    fn test_remove_knot_of_step_function() {
+        let mut curve = NurbsCurve::new(0);
+        for x in [0.0, 1.0, 1.0 + 1e-9, 3.0] {
+            curve.add_control_point(ControlPoint::new(x, 0.0, 1.0));
+        }
+        assert_eq!(curve.get_knots(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        // Only the step between the two nearly equal points is small enough
+        assert_eq!(curve.remove_knot(0.25, 1, 1e-6), Ok(0));
+        assert_eq!(curve.remove_knot(0.5, 1, 1e-6), Ok(1));
+        assert_eq!(curve.get_knots(), vec![0.0, 0.25, 0.75, 1.0]);
+        assert_eq!(curve.num_control_points(), 3);
+        assert_eq!(curve.evaluate(0.6).unwrap().x(), 1.0);
+        assert_eq!(curve.evaluate(0.8).unwrap().x(), 3.0);
+
+        // Removing both steps would move the last part of the curve by 3
+        assert_eq!(curve.simplify(1e-6), Ok(0));
+        assert_eq!(curve.simplify(2.0), Ok(1));
+        assert_eq!(curve.num_control_points(), 2);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+        curve.refine_knots(&[0.1, 0.2, 0.5, 0.5, 0.8, 0.9]).unwrap();
+        assert_eq!(curve.num_control_points(), 12);
+
+        // Every inserted knot goes away again, the original ones stay
+        assert_eq!(curve.simplify(1e-9), Ok(6));
+        assert_eq!(curve.num_control_points(), 6);
+        assert_same_shape(&original, &curve);
+
+        // A loose tolerance removes original knots of a gently curving curve, keeping the deviation bounded
+        let mut wavy = NurbsCurve::new(3);
+        for i in 0..12 {
+            let x = i as f64;
+            wavy.add_control_point(ControlPoint::new(x, 0.02 * x.sin(), 1.0));
+        }
+        let before = wavy.clone();
+        let tolerance = 0.05;
+        let removed = wavy.simplify(tolerance).unwrap();
+        assert!(removed > 0);
+        assert_eq!(wavy.num_control_points(), 12 - removed);
+        for i in 0..=200 {
+            let u = i as f64 / 200.0;
+            let a = before.evaluate(u).unwrap();
+            let b = wavy.evaluate(u).unwrap();
+            assert!((a.x() - b.x()).hypot(a.y() - b.y()) <= tolerance);
+        }
+    }
+}
@@ -1,7 +1,36 @@
 use wasm_bindgen::prelude::*;
 use std::vec::Vec;
 use std::f64;
-use std::arch::wasm32::*;
+
+mod analysis;
+mod arc_length;
+mod batch;
+mod conics;
+mod core;
+mod curve3d;
+mod degree;
+mod editing;
+mod error;
+mod evaluator;
+mod fitting;
+mod knot_strategy;
+mod knots;
+mod periodic;
+mod projection;
+mod simd;
+mod split;
+mod surface;
+mod tessellation;
+
+pub use analysis::CurvatureSamples;
+pub use curve3d::{ControlPoint3, NurbsCurve3};
+pub use error::{KnotError, NurbsError};
+pub use evaluator::CurveEvaluator;
+pub use fitting::{CurveFit, Parameterization};
+pub use knot_strategy::KnotStrategy;
+pub use projection::ClosestPoint;
+pub use surface::NurbsSurface;
+pub use tessellation::SurfaceMesh;
 
 // When the `dlmalloc` feature is enabled, use `dlmalloc` as the global allocator.
 #[cfg(feature = "dlmalloc")]
@@ -61,12 +90,25 @@ impl ControlPoint {
     }
 }
 
+impl core::WeightedPoint<2> for ControlPoint {
+    fn coordinates(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
 // Define the NURBS curve generator
 #[wasm_bindgen]
+#[derive(Clone, Debug)]
 pub struct NurbsCurve {
     control_points: Vec<ControlPoint>,
     knots: Vec<f64>,
     degree: usize,
+    periodic: bool,
+    knot_strategy: KnotStrategy,
 }
 
 #[wasm_bindgen]
@@ -77,418 +119,104 @@ impl NurbsCurve {
             control_points: Vec::new(),
             knots: Vec::new(),
             degree,
+            periodic: false,
+            knot_strategy: KnotStrategy::UniformClamped,
         }
     }
 
     // Add a control point to the curve
+    // A periodic curve takes the point into its loop, just before the wrapped copies
     pub fn add_control_point(&mut self, control_point: ControlPoint) {
+        if self.periodic {
+            let mut points = self.control_points[..self.distinct_control_points()].to_vec();
+            points.push(control_point);
+            self.wrap_periodic(points);
+            return;
+        }
+
         self.control_points.push(control_point);
         self.update_knots();
     }
 
-    // Set the knot vector manually
-    pub fn set_knots(&mut self, knots: Vec<f64>) {
+    // Set the knot vector manually, this leaves periodic mode and locks the knots
+    pub fn set_knots(&mut self, knots: Vec<f64>) -> Result<(), NurbsError> {
+        core::validate_knots(&knots, self.effective_degree(), self.control_points.len())?;
         self.knots = knots;
+        self.periodic = false;
+        self.knot_strategy = KnotStrategy::Locked;
+        Ok(())
     }
 
-    // Generate a uniform knot vector
+    // Regenerate the knot vector following the knot strategy
     fn update_knots(&mut self) {
-        // Safety check: ensure we have at least one control point
-        if self.control_points.is_empty() {
-            self.knots = vec![0.0, 1.0]; // Default knot vector
-            return;
-        }
-        
-        let n = self.control_points.len() - 1;
-        
-        // Safety check: ensure degree is not too large for the number of control points
-        if self.degree > n {
-            // Adjust degree to be at most n
-            // This is just for knot generation, we don't actually change the curve's degree
-            let effective_degree = n;
-            let m = n + effective_degree + 1;
-            
-            let mut knots = Vec::with_capacity(m + 1);
-            
-            // For a clamped knot vector
-            for i in 0..=m {
-                let knot = if i < effective_degree {
-                    0.0
-                } else if i > n {
-                    1.0
-                } else {
-                    let denom = (n - effective_degree + 1) as f64;
-                    if denom < 1e-10 {
-                        // Avoid division by zero
-                        i as f64 / m as f64
-                    } else {
-                        (i - effective_degree) as f64 / denom
-                    }
-                };
-                knots.push(knot);
-            }
-            
-            self.knots = knots;
-        } else {
-            // Normal case: degree <= n
-            let m = n + self.degree + 1;
-            
-            let mut knots = Vec::with_capacity(m + 1);
-            
-            // For a clamped knot vector
-            for i in 0..=m {
-                let knot = if i < self.degree {
-                    0.0
-                } else if i > n {
-                    1.0
-                } else {
-                    let denom = (n - self.degree + 1) as f64;
-                    if denom < 1e-10 {
-                        // Avoid division by zero
-                        i as f64 / m as f64
-                    } else {
-                        (i - self.degree) as f64 / denom
-                    }
-                };
-                knots.push(knot);
-            }
-            
+        if let Some(knots) = self.generated_knots() {
             self.knots = knots;
         }
     }
 
     // Find the knot span for a given parameter u
     fn find_span(&self, u: f64) -> Option<usize> {
-        // Check if we have enough control points and knots
-        if self.control_points.is_empty() || self.knots.len() < 2 {
-            return None;
-        }
-        
-        let n = self.control_points.len() - 1;
-        
-        // Ensure we have enough knots for the degree
-        if n + 1 >= self.knots.len() || self.degree >= self.knots.len() {
-            return None;
-        }
-        
-        // Clamp u to valid range
-        let u = u.max(0.0).min(1.0);
-        
-        if u >= self.knots[n + 1] {
-            return Some(n);
-        }
-        
-        if u <= self.knots[self.degree] {
-            return Some(self.degree);
-        }
-        
-        let mut low = self.degree;
-        let mut high = n + 1;
-        
-        // Safety check to prevent infinite loop
-        let max_iterations = 100;
-        let mut iterations = 0;
-        
-        let mut mid = (low + high) / 2;
-        
-        while (u < self.knots[mid] || u >= self.knots[mid + 1]) && iterations < max_iterations {
-            if u < self.knots[mid] {
-                high = mid;
-            } else {
-                low = mid;
-            }
-            mid = (low + high) / 2;
-            iterations += 1;
-        }
-        
-        // If we hit max iterations, return a safe value
-        if iterations >= max_iterations {
-            return Some(self.degree);
-        }
-        
-        Some(mid)
+        core::find_span(&self.knots, self.effective_degree(), self.control_points.len(), u)
     }
 
-    // Calculate the basis functions for a given parameter u and span
-    // SIMD-optimized implementation
-    fn calculate_basis_functions(&self, span: usize, u: f64) -> Vec<f64> {
-        // Safety check: ensure we have enough knots
-        if span + self.degree >= self.knots.len() || span < self.degree {
-            return vec![0.0; self.degree + 1];
+    // Parameter intervals between consecutive distinct knots of the curve domain
+    fn knot_spans(&self) -> Vec<(f64, f64)> {
+        let p = self.effective_degree();
+        if self.control_points.is_empty() || self.knots.len() < self.control_points.len() + 1 {
+            return Vec::new();
         }
-        
-        let mut basis = vec![0.0; self.degree + 1];
-        let mut left = vec![0.0; self.degree + 1];
-        let mut right = vec![0.0; self.degree + 1];
-        
-        basis[0] = 1.0;
-        
-        // SIMD-optimized Cox-de Boor recursion formula
-        for j in 1..=self.degree {
-            // Safety check: ensure indices are valid
-            if span + 1 < j || span + j >= self.knots.len() {
-                continue;
-            }
-            
-            left[j] = u - self.knots[span + 1 - j];
-            right[j] = self.knots[span + j] - u;
-            
-            let mut saved = 0.0;
-            
-            // Process basis functions in pairs when possible for SIMD
-            let mut r = 0;
-            while r + 1 < j {
-                // Load pairs of values for SIMD processing
-                let right_vec = f64x2(right[r + 1], right[r + 2]);
-                let left_vec = f64x2(left[j - r], left[j - r - 1]);
-                let basis_vec = f64x2(basis[r], basis[r + 1]);
-                
-                // Calculate divisors using SIMD
-                let divisor_vec = f64x2_add(right_vec, left_vec);
-                
-                // Check for near-zero divisors and compute temps
-                let divisor0 = f64x2_extract_lane::<0>(divisor_vec);
-                let divisor1 = f64x2_extract_lane::<1>(divisor_vec);
-                
-                let temp0 = if divisor0.abs() < 1e-10 { 0.0 } else { basis[r] / divisor0 };
-                let temp1 = if divisor1.abs() < 1e-10 { 0.0 } else { basis[r + 1] / divisor1 };
-                
-                // Update basis values
-                basis[r] = saved + right[r + 1] * temp0;
-                basis[r + 1] = left[j - r] * temp0 + right[r + 2] * temp1;
-                saved = left[j - r - 1] * temp1;
-                
-                r += 2;
-            }
-            
-            // Handle remaining single element if j is odd
-            if r < j {
-                let divisor = right[r + 1] + left[j - r];
-                let temp = if divisor.abs() < 1e-10 {
-                    0.0
-                } else {
-                    basis[r] / divisor
-                };
-                
-                basis[r] = saved + right[r + 1] * temp;
-                saved = left[j - r] * temp;
-            }
-            
-            basis[j] = saved;
-        }
-        
-        basis
+
+        let n = self.control_points.len() - 1;
+
+        self.knots[p..=n + 1]
+            .windows(2)
+            .filter(|pair| pair[1] > pair[0])
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
     }
 
     // Evaluate the NURBS curve at parameter u
-    // Standard implementation for better accuracy
-    pub fn evaluate(&self, u: f64) -> Option<ControlPoint> {
-        if self.control_points.is_empty() || self.knots.is_empty() {
-            return None;
-        }
-        
-        // Find the knot span for parameter u
-        let span = match self.find_span(u) {
-            Some(span) => span,
-            None => return None, // Return None if we couldn't find a valid span
-        };
-        
-        // Safety check: ensure we have enough control points for the calculation
-        if span < self.degree || span >= self.control_points.len() {
-            return None;
-        }
-        
-        // Calculate basis functions
-        let basis = self.calculate_basis_functions(span, u);
-        
-        // Standard evaluation of NURBS curve point
-        let mut numerator_x = 0.0;
-        let mut numerator_y = 0.0;
-        let mut denominator = 0.0;
-        
-        // SIMD-optimized evaluation using vectorized operations
-        // Process control points in batches of 2 for SIMD efficiency
-        let mut i = 0;
-        while i + 1 <= self.degree {
-            let idx1 = span - self.degree + i;
-            let idx2 = span - self.degree + i + 1;
-            
-            if idx1 < self.control_points.len() && idx2 < self.control_points.len() {
-                // Load two control points and their weights
-                let cp1 = &self.control_points[idx1];
-                let cp2 = &self.control_points[idx2];
-                
-                // Create SIMD vectors for x coordinates
-                let x_vec = f64x2(cp1.x, cp2.x);
-                let y_vec = f64x2(cp1.y, cp2.y);
-                let weight_vec = f64x2(cp1.weight, cp2.weight);
-                let basis_vec = f64x2(basis[i], basis[i + 1]);
-                
-                // SIMD multiplication
-                let weighted_basis_vec = f64x2_mul(basis_vec, weight_vec);
-                let x_contrib = f64x2_mul(weighted_basis_vec, x_vec);
-                let y_contrib = f64x2_mul(weighted_basis_vec, y_vec);
-                
-                // Extract and accumulate results
-                numerator_x += f64x2_extract_lane::<0>(x_contrib) + f64x2_extract_lane::<1>(x_contrib);
-                numerator_y += f64x2_extract_lane::<0>(y_contrib) + f64x2_extract_lane::<1>(y_contrib);
-                denominator += f64x2_extract_lane::<0>(weighted_basis_vec) + f64x2_extract_lane::<1>(weighted_basis_vec);
-            } else if idx1 < self.control_points.len() {
-                // Handle single remaining control point
-                let control_point = &self.control_points[idx1];
-                let weight = control_point.weight;
-                let basis_value = basis[i];
-                let weighted_basis = basis_value * weight;
-                
-                numerator_x += weighted_basis * control_point.x;
-                numerator_y += weighted_basis * control_point.y;
-                denominator += weighted_basis;
-            }
-            
-            i += 2;
-        }
-        
-        // Handle any remaining control point if degree is odd
-        if i <= self.degree {
-            let control_point_idx = span - self.degree + i;
-            if control_point_idx < self.control_points.len() {
-                let control_point = &self.control_points[control_point_idx];
-                let weight = control_point.weight;
-                let basis_value = basis[i];
-                let weighted_basis = basis_value * weight;
-                
-                numerator_x += weighted_basis * control_point.x;
-                numerator_y += weighted_basis * control_point.y;
-                denominator += weighted_basis;
-            }
-        }
-        
-        // Avoid division by zero
-        if denominator.abs() < 1e-10 {
-            return None;
-        }
-        
-        Some(ControlPoint::new(
-            numerator_x / denominator,
-            numerator_y / denominator,
-            1.0, // The evaluated point has a weight of 1.0
-        ))
+    // Fails for parameters outside the domain, a periodic curve wraps them into it first
+    pub fn evaluate(&self, u: f64) -> Result<ControlPoint, NurbsError> {
+        let u = self.wrap_parameter(u);
+        let [x, y] = core::evaluate(&self.knots, self.effective_degree(), &self.control_points, u)?;
+
+        // The evaluated point has a weight of 1.0
+        Ok(ControlPoint::new(x, y, 1.0))
     }
 
-    // Generate points along the curve for rendering
-    // SIMD-optimized version for batch processing
-    pub fn generate_points(&self, num_points: usize) -> Vec<ControlPoint> {
-        let mut points = Vec::with_capacity(num_points);
-        
-        if self.control_points.len() < self.degree + 1 || self.knots.is_empty() {
-            return points;
-        }
-        
-        // Ensure we have at least 2 points for interpolation
-        let actual_num_points = if num_points < 2 { 2 } else { num_points };
-        
-        // Pre-allocate the points vector
-        points.resize(actual_num_points, ControlPoint::new(0.0, 0.0, 1.0));
-        
-        // Calculate step size
-        let step = 1.0 / (actual_num_points as f64 - 1.0);
-        
-        // SIMD-optimized batch point generation
-        // Process multiple parameter values in parallel when possible
-        let mut i = 0;
-        while i + 3 < actual_num_points {
-            // Calculate 4 parameter values at once
-            let u0 = i as f64 * step;
-            let u1 = (i + 1) as f64 * step;
-            let u2 = (i + 2) as f64 * step;
-            let u3 = (i + 3) as f64 * step;
-            
-            // Evaluate points (still sequential but with better cache locality)
-            match self.evaluate(u0) {
-                Some(point) => points[i] = point,
-                None => {
-                    if !self.control_points.is_empty() {
-                        points[i] = self.control_points[0].clone();
-                    } else {
-                        points[i] = ControlPoint::new(0.0, 0.0, 1.0);
-                    }
-                }
-            }
-            
-            match self.evaluate(u1) {
-                Some(point) => points[i + 1] = point,
-                None => {
-                    if !self.control_points.is_empty() {
-                        points[i + 1] = self.control_points[0].clone();
-                    } else {
-                        points[i + 1] = ControlPoint::new(0.0, 0.0, 1.0);
-                    }
-                }
-            }
-            
-            match self.evaluate(u2) {
-                Some(point) => points[i + 2] = point,
-                None => {
-                    if !self.control_points.is_empty() {
-                        points[i + 2] = self.control_points[0].clone();
-                    } else {
-                        points[i + 2] = ControlPoint::new(0.0, 0.0, 1.0);
-                    }
-                }
-            }
-            
-            match self.evaluate(u3) {
-                Some(point) => points[i + 3] = point,
-                None => {
-                    if !self.control_points.is_empty() {
-                        points[i + 3] = self.control_points[0].clone();
-                    } else {
-                        points[i + 3] = ControlPoint::new(0.0, 0.0, 1.0);
-                    }
-                }
-            }
-            
-            i += 4;
-        }
-        
-        // Process remaining points
-        while i < actual_num_points {
-            let u = if i == actual_num_points - 1 {
-                1.0 // Ensure the last point is exactly at u=1.0
-            } else {
-                i as f64 * step
-            };
-            
-            match self.evaluate(u) {
-                Some(point) => points[i] = point,
-                None => {
-                    if !self.control_points.is_empty() {
-                        points[i] = self.control_points[0].clone();
-                    } else {
-                        points[i] = ControlPoint::new(0.0, 0.0, 1.0);
-                    }
-                }
-            }
-            
-            i += 1;
-        }
-        
-        // This section is no longer needed as we're processing all points sequentially above
-        
-        // Ensure the last point is exactly at u=1.0
-        if actual_num_points > 0 {
-            match self.evaluate(1.0) {
-                Some(point) => points[actual_num_points - 1] = point,
-                None => {
-                    // If evaluation fails, use the last control point as fallback
-                    if !self.control_points.is_empty() {
-                        points[actual_num_points - 1] = self.control_points.last().unwrap().clone();
-                    }
-                }
-            }
+    // Evaluate the curve point and its derivatives up to the given order at parameter u
+    // Returns a flat array [x, y, dx, dy, ddx, ddy, ...] with (order + 1) pairs
+    pub fn derivatives(&self, u: f64, order: usize) -> Result<Box<[f64]>, NurbsError> {
+        let ders = self.rational_derivatives(u, order)?;
+
+        let mut result = Vec::with_capacity(ders.len() * 2);
+        for d in ders.iter() {
+            result.push(d[0]);
+            result.push(d[1]);
         }
-        
-        points
+
+        Ok(result.into_boxed_slice())
+    }
+
+    // Compute the derivatives of the rational curve up to the given order
+    // Entry k holds the k-th derivative, entry 0 is the curve point itself
+    fn rational_derivatives(&self, u: f64, order: usize) -> Result<Vec<[f64; 2]>, NurbsError> {
+        let u = self.wrap_parameter(u);
+        core::rational_derivatives(&self.knots, self.effective_degree(), &self.control_points, u, order)
+    }
+
+    // Generate evenly spaced points along the curve domain for rendering
+    // At least two points are generated, the last one lies exactly at the end of the domain
+    pub fn generate_points(&self, num_points: usize) -> Result<Vec<ControlPoint>, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        let params = core::uniform_parameters(start, end, num_points);
+
+        let flat = self.evaluate_many(&params)?;
+        Ok(flat
+            .chunks_exact(2)
+            .map(|point| ControlPoint::new(point[0], point[1], 1.0))
+            .collect())
     }
 
     // Get the number of control points
@@ -497,28 +225,148 @@ impl NurbsCurve {
     }
 
     // Get a control point by index
-    pub fn get_control_point(&self, index: usize) -> Option<ControlPoint> {
-        if index < self.control_points.len() {
-            Some(self.control_points[index].clone())
-        } else {
-            None
-        }
+    pub fn get_control_point(&self, index: usize) -> Result<ControlPoint, NurbsError> {
+        self.control_points
+            .get(index)
+            .cloned()
+            .ok_or(NurbsError::IndexOutOfRange { index, len: self.control_points.len() })
     }
 
     // Update a control point at a specific index
-    pub fn update_control_point(&mut self, index: usize, x: f64, y: f64, weight: f64) -> bool {
-        if index < self.control_points.len() {
-            self.control_points[index] = ControlPoint::new(x, y, weight);
-            true
-        } else {
-            false
+    pub fn update_control_point(&mut self, index: usize, x: f64, y: f64, weight: f64) -> Result<(), NurbsError> {
+        let len = self.control_points.len();
+        let point = self.control_points.get_mut(index).ok_or(NurbsError::IndexOutOfRange { index, len })?;
+        *point = ControlPoint::new(x, y, weight);
+
+        // Keep the wrapped copies of a periodic curve in step
+        if self.periodic {
+            self.sync_wrapped_points(index);
         }
+        Ok(())
     }
 
-    // Get the degree of the curve
+    // Get the degree of the curve as requested
     pub fn get_degree(&self) -> usize {
         self.degree
     }
+
+    // Degree the curve is evaluated with, lowered to one less than the number of control points
+    // while there are too few points for the requested degree
+    pub fn effective_degree(&self) -> usize {
+        self.degree.min(self.control_points.len().saturating_sub(1))
+    }
+
+    // Change the degree while keeping the control points, the knots follow the knot strategy
+    // Locked knots stay as they are and must be replaced with set_knots to suit the new degree.
+    // A periodic curve rewraps its loop, which needs a degree of at least 1
+    pub fn set_degree(&mut self, degree: usize) -> Result<(), NurbsError> {
+        if self.periodic && degree == 0 {
+            return Err(NurbsError::InvalidDegree {
+                degree,
+                num_points: self.distinct_control_points(),
+            });
+        }
+
+        let points = self.distinct_points();
+        self.degree = degree;
+        self.replace_control_points(points);
+        Ok(())
+    }
+
+    // Get a copy of the knot vector
+    pub fn get_knots(&self) -> Vec<f64> {
+        self.knots.clone()
+    }
+
+    // Get the parameter domain [start, end] over which the curve is defined
+    pub fn domain(&self) -> Result<Box<[f64]>, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        Ok(Box::new([start, end]))
+    }
+
+    // Reverse the direction of the curve without changing its shape or domain
+    pub fn reverse(&mut self) {
+        // Reflect the knots about the middle of the domain
+        let reflection = match self.parameter_range() {
+            Some((start, end)) => start + end,
+            None => self.knots.first().copied().unwrap_or(0.0) + self.knots.last().copied().unwrap_or(0.0),
+        };
+
+        self.control_points.reverse();
+        self.knots = self.knots.iter().rev().map(|&knot| reflection - knot).collect();
+    }
+
+    // Map the curve domain linearly onto [new_start, new_end] without changing its shape
+    // Fails if the new domain is empty or the curve has no valid domain
+    pub fn reparameterize(&mut self, new_start: f64, new_end: f64) -> Result<(), NurbsError> {
+        let (start, end) = self.checked_range()?;
+        if end <= start {
+            return Err(KnotError::EmptyDomain.into());
+        }
+        if !new_start.is_finite() || !new_end.is_finite() || new_end <= new_start {
+            return Err(NurbsError::InvalidArgument("the new domain must be finite and non-empty"));
+        }
+
+        // The end of the domain is pinned exactly, the start maps exactly by construction
+        let scale = (new_end - new_start) / (end - start);
+        for knot in self.knots.iter_mut() {
+            *knot = if *knot == end { new_end } else { new_start + (*knot - start) * scale };
+        }
+
+        Ok(())
+    }
+}
+
+impl NurbsCurve {
+    // Open curve with the given control points and knots, locked so adding points keeps them
+    fn from_parts(control_points: Vec<ControlPoint>, knots: Vec<f64>, degree: usize) -> NurbsCurve {
+        NurbsCurve {
+            control_points,
+            knots,
+            knot_strategy: KnotStrategy::Locked,
+            ..NurbsCurve::new(degree)
+        }
+    }
+
+    // First and last parameter of the curve domain
+    fn parameter_range(&self) -> Option<(f64, f64)> {
+        core::parameter_range(&self.knots, self.effective_degree(), self.control_points.len())
+    }
+
+    // First and last parameter of the curve domain, or why the curve cannot be evaluated
+    fn checked_range(&self) -> Result<(f64, f64), NurbsError> {
+        core::check_curve(&self.knots, self.effective_degree(), self.control_points.len())
+    }
+
+    // Control points in homogeneous form (w*x, w*y, w)
+    fn homogeneous_points(&self) -> Vec<[f64; 3]> {
+        self.control_points
+            .iter()
+            .map(|cp| [cp.x * cp.weight, cp.y * cp.weight, cp.weight])
+            .collect()
+    }
+
+    // Replace the control points from their homogeneous form
+    // The new points no longer follow the periodic layout
+    fn set_homogeneous_points(&mut self, points: &[[f64; 3]]) {
+        self.periodic = false;
+        self.control_points = points
+            .iter()
+            .map(|&[wx, wy, w]| {
+                // Zero weights have no Euclidean image, keep the raw coordinates
+                if w.abs() < 1e-10 {
+                    ControlPoint::new(wx, wy, w)
+                } else {
+                    ControlPoint::new(wx / w, wy / w, w)
+                }
+            })
+            .collect();
+    }
+
+    // Number of times u appears in the knot vector
+    fn knot_multiplicity(&self, u: f64) -> usize {
+        self.knots.iter().filter(|&&knot| knot == u).count()
+    }
 }
 
 // Helper function to create a JS array of points from Rust Vec
@@ -530,7 +378,7 @@ pub fn generate_nurbs_curve_points(
     weights: &[f64],
     degree: usize,
     num_points: usize
-) -> Box<[f64]> {
+) -> Result<Box<[f64]>, NurbsError> {
     // Initialize panic hook for better error messages
     #[cfg(feature = "console_error_panic_hook")]
     init_panic_hook();
@@ -549,38 +397,9 @@ pub fn generate_nurbs_curve_points(
         ));
     }
     
-    // Generate points
-    let points = curve.generate_points(num_points);
-    
-    // Convert to flat array [x1, y1, x2, y2, ...]
-    let mut result = Vec::with_capacity(points.len() * 2);
-    
-    // Pre-allocate the result vector
-    result.resize(points.len() * 2, 0.0);
-    
-    // SIMD-optimized conversion to flat array
-    let mut i = 0;
-    while i + 1 < points.len() {
-        // Process two points at once using SIMD
-        let x_vec = f64x2(points[i].x, points[i + 1].x);
-        let y_vec = f64x2(points[i].y, points[i + 1].y);
-        
-        // Store interleaved x,y values
-        result[i * 2] = f64x2_extract_lane::<0>(x_vec);
-        result[i * 2 + 1] = f64x2_extract_lane::<0>(y_vec);
-        result[i * 2 + 2] = f64x2_extract_lane::<1>(x_vec);
-        result[i * 2 + 3] = f64x2_extract_lane::<1>(y_vec);
-        
-        i += 2;
-    }
-    
-    // Handle remaining point if odd number
-    if i < points.len() {
-        result[i * 2] = points[i].x;
-        result[i * 2 + 1] = points[i].y;
-    }
-    
-    result.into_boxed_slice()
+    // Generate points as a flat array [x1, y1, x2, y2, ...]
+    let (start, end) = curve.checked_range()?;
+    curve.evaluate_many(&core::uniform_parameters(start, end, num_points))
 }
 
 #[cfg(test)]
@@ -632,7 +451,7 @@ mod tests {
         curve.add_control_point(ControlPoint::new(1.0, 2.0, 3.0));
         
         let success = curve.update_control_point(0, 4.0, 5.0, 6.0);
-        assert!(success);
+        assert!(success.is_ok());
         
         let point = curve.get_control_point(0).unwrap();
         assert_eq!(point.x(), 4.0);
@@ -641,7 +460,7 @@ mod tests {
         
         // Test updating non-existent point
         let failure = curve.update_control_point(1, 7.0, 8.0, 9.0);
-        assert!(!failure);
+        assert_eq!(failure, Err(NurbsError::IndexOutOfRange { index: 1, len: 1 }));
     }
 
     #[test]
@@ -672,7 +491,7 @@ mod tests {
         curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
         curve.add_control_point(ControlPoint::new(10.0, 10.0, 1.0));
         
-        let points = curve.generate_points(3);
+        let points = curve.generate_points(3).unwrap();
         assert_eq!(points.len(), 3);
         
         // First point should be at u=0.0
@@ -708,7 +527,7 @@ mod tests {
             &weights,
             1, // Linear curve
             3  // 3 points
-        );
+        ).unwrap();
         
         assert_eq!(result.len(), 6); // 3 points * 2 coordinates
         
@@ -720,4 +539,278 @@ mod tests {
         assert_eq!(result[4], 10.0);
         assert_eq!(result[5], 10.0);
     }
+
+    fn weighted_cubic() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 5.0, 2.0));
+        curve.add_control_point(ControlPoint::new(5.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(8.0, 4.0, 1.5));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        curve
+    }
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        let curve = weighted_cubic();
+        let h = 1e-5;
+
+        for &u in &[0.1, 0.3, 0.45, 0.6, 0.85] {
+            let ders = curve.derivatives(u, 2).unwrap();
+            assert_eq!(ders.len(), 6);
+
+            // Zeroth derivative is the curve point itself
+            let point = curve.evaluate(u).unwrap();
+            assert!((ders[0] - point.x()).abs() < 1e-12);
+            assert!((ders[1] - point.y()).abs() < 1e-12);
+
+            let before = curve.evaluate(u - h).unwrap();
+            let after = curve.evaluate(u + h).unwrap();
+
+            let dx = (after.x() - before.x()) / (2.0 * h);
+            let dy = (after.y() - before.y()) / (2.0 * h);
+            assert!((ders[2] - dx).abs() < 1e-5, "dx at {}: {} vs {}", u, ders[2], dx);
+            assert!((ders[3] - dy).abs() < 1e-5, "dy at {}: {} vs {}", u, ders[3], dy);
+
+            let ddx = (after.x() - 2.0 * point.x() + before.x()) / (h * h);
+            let ddy = (after.y() - 2.0 * point.y() + before.y()) / (h * h);
+            assert!((ders[4] - ddx).abs() < 1e-2, "ddx at {}: {} vs {}", u, ders[4], ddx);
+            assert!((ders[5] - ddy).abs() < 1e-2, "ddy at {}: {} vs {}", u, ders[5], ddy);
+        }
+    }
+
+    #[test]
+    fn test_higher_order_derivatives_of_rational_curve() {
+        let curve = weighted_cubic();
+        let h = 1e-4;
+        let u = 0.4;
+
+        // Derivatives above the degree are non-zero for rational curves
+        let ders = curve.derivatives(u, 4).unwrap();
+        let before = curve.derivatives(u - h, 3).unwrap();
+        let after = curve.derivatives(u + h, 3).unwrap();
+
+        let d4x = (after[6] - before[6]) / (2.0 * h);
+        let d4y = (after[7] - before[7]) / (2.0 * h);
+        assert!((ders[8] - d4x).abs() < 1e-3 * d4x.abs().max(1.0));
+        assert!((ders[9] - d4y).abs() < 1e-3 * d4y.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_derivatives_of_line() {
+        let mut curve = NurbsCurve::new(1);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(10.0, 4.0, 1.0));
+
+        let ders = curve.derivatives(0.25, 2).unwrap();
+        assert_eq!(&ders[..], &[2.5, 1.0, 10.0, 4.0, 0.0, 0.0]);
+
+        // Empty curves cannot be differentiated
+        assert!(NurbsCurve::new(3).derivatives(0.5, 1).is_err());
+    }
+
+    fn curve_on_domain(start: f64, end: f64) -> NurbsCurve {
+        let mut curve = weighted_cubic();
+        let unit = curve.get_knots();
+        curve.set_knots(unit.iter().map(|k| start + k * (end - start)).collect()).unwrap();
+        curve
+    }
+
+    #[test]
+    fn test_arbitrary_domain() {
+        let unit = weighted_cubic();
+        let curve = curve_on_domain(0.0, 17.3);
+
+        assert_eq!(&curve.domain().unwrap()[..], &[0.0, 17.3]);
+
+        // The same shape is traced at proportional parameters
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            let a = unit.evaluate(t).unwrap();
+            let b = curve.evaluate(t * 17.3).unwrap();
+            assert!((a.x() - b.x()).abs() < 1e-9);
+            assert!((a.y() - b.y()).abs() < 1e-9);
+        }
+
+        // Points are generated across the whole domain
+        let points = curve.generate_points(11).unwrap();
+        let expected = unit.generate_points(11).unwrap();
+        for (a, b) in points.iter().zip(expected.iter()) {
+            assert!((a.x() - b.x()).abs() < 1e-9);
+            assert!((a.y() - b.y()).abs() < 1e-9);
+        }
+        let last = curve.get_control_point(4).unwrap();
+        assert_eq!((points[10].x(), points[10].y()), (last.x(), last.y()));
+
+        // Parameters outside the domain have no point
+        assert!(matches!(
+            curve.evaluate(-5.0),
+            Err(NurbsError::ParameterOutOfDomain { u, start: 0.0, end: 17.3 }) if u == -5.0
+        ));
+        assert!(curve.evaluate(17.3 + 1e-9).is_err());
+        assert!(curve.derivatives(-1e-9, 1).is_err());
+    }
+
+    #[test]
+    fn test_effective_degree_follows_point_count() {
+        let mut curve = NurbsCurve::new(3);
+        assert_eq!(curve.effective_degree(), 0);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(4.0, 2.0, 1.0));
+
+        // Two points evaluate as a line until there are enough for the cubic
+        assert_eq!(curve.get_degree(), 3);
+        assert_eq!(curve.effective_degree(), 1);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 1.0, 1.0]);
+        let middle = curve.evaluate(0.5).unwrap();
+        assert_eq!((middle.x(), middle.y()), (2.0, 1.0));
+        assert_eq!(curve.domain().unwrap().to_vec(), vec![0.0, 1.0]);
+
+        curve.add_control_point(ControlPoint::new(8.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(12.0, 2.0, 1.0));
+        assert_eq!(curve.effective_degree(), 3);
+        assert_eq!(curve.get_knots().len(), 8);
+
+        // Knot insertion on a lowered curve keeps the degree it was evaluated with
+        let mut short = NurbsCurve::new(3);
+        short.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        short.add_control_point(ControlPoint::new(4.0, 2.0, 1.0));
+        assert_eq!(short.insert_knot(0.5, 1), Ok(1));
+        assert_eq!(short.get_degree(), 1);
+        assert_eq!(short.effective_degree(), 1);
+        assert!((short.evaluate(0.25).unwrap().x() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_set_degree() {
+        let mut curve = weighted_cubic();
+        curve.set_degree(2).unwrap();
+        assert_eq!(curve.get_degree(), 2);
+        assert_eq!(curve.num_control_points(), 5);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0]);
+
+        // Beyond the point count the curve keeps working at the highest degree it supports
+        curve.set_degree(7).unwrap();
+        assert_eq!(curve.get_degree(), 7);
+        assert_eq!(curve.effective_degree(), 4);
+        assert_eq!(curve.get_knots(), vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        let start = curve.evaluate(0.0).unwrap();
+        assert_eq!((start.x(), start.y()), (0.0, 0.0));
+
+        // Periodic loops rewrap with the new degree
+        curve.set_degree(2).unwrap();
+        curve.set_periodic(true).unwrap();
+        assert_eq!(curve.num_control_points(), 7);
+        curve.set_degree(3).unwrap();
+        assert!(curve.is_periodic());
+        assert_eq!(curve.num_control_points(), 8);
+        assert!(curve.is_closed());
+        assert!(curve.set_degree(0).is_err());
+        assert_eq!(curve.get_degree(), 3);
+
+        // Locked knots no longer fit until new ones are set
+        let mut locked = weighted_cubic();
+        locked.set_knots(vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]).unwrap();
+        locked.set_degree(2).unwrap();
+        assert!(matches!(locked.evaluate(0.5), Err(NurbsError::InvalidKnots(_))));
+    }
+
+    #[test]
+    fn test_errors_name_their_cause() {
+        let empty = NurbsCurve::new(3);
+        assert_eq!(empty.evaluate(0.5).unwrap_err(), NurbsError::EmptyCurve);
+        assert_eq!(empty.domain().unwrap_err().kind(), "EmptyCurve");
+
+        let curve = weighted_cubic();
+        let error = curve.evaluate(f64::NAN).unwrap_err();
+        assert_eq!(error.kind(), "ParameterOutOfDomain");
+        assert_eq!(
+            curve.get_control_point(9).unwrap_err(),
+            NurbsError::IndexOutOfRange { index: 9, len: 5 }
+        );
+
+        let mut short = NurbsCurve::new(3);
+        short.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        short.add_control_point(ControlPoint::new(1.0, 0.0, 1.0));
+        assert!(matches!(short.reduce_degree(1.0), Err(NurbsError::InvalidDegree { .. })));
+
+        let message = NurbsError::IndexOutOfRange { index: 9, len: 5 }.to_string();
+        assert_eq!(message, "index 9 is out of range for 5 control points");
+        let message = NurbsError::from(KnotError::EmptyDomain).to_string();
+        assert_eq!(message, "invalid knot vector: the knots leave the curve with an empty domain");
+    }
+
+    #[test]
+    fn test_set_knots_rejects_invalid_vectors() {
+        let mut curve = weighted_cubic();
+        let original = curve.get_knots();
+
+        let cases = [
+            (vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0], KnotError::WrongLength { expected: 9, actual: 8 }),
+            (vec![0.0, 0.0, 0.0, 0.0, f64::NAN, 1.0, 1.0, 1.0, 1.0], KnotError::NotFinite { index: 4 }),
+            (vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.2, 1.0, 1.0, 1.0], KnotError::Decreasing { index: 5 }),
+            (
+                vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+                KnotError::MultiplicityTooHigh { value: 0.0, multiplicity: 5, max: 4 },
+            ),
+            (
+                vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 0.5, 1.0],
+                KnotError::MultiplicityTooHigh { value: 0.5, multiplicity: 4, max: 3 },
+            ),
+            (vec![0.0, 1.0, 2.0, 3.0, 3.0, 3.0, 4.0, 5.0, 6.0], KnotError::EmptyDomain),
+        ];
+        for (knots, expected) in cases {
+            assert_eq!(curve.set_knots(knots), Err(NurbsError::InvalidKnots(expected)));
+        }
+
+        // Rejected vectors leave the curve untouched
+        assert_eq!(curve.get_knots(), original);
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::UniformClamped);
+
+        // Unclamped vectors and full multiplicity at the ends are fine
+        assert!(curve.set_knots(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).is_ok());
+        assert!(curve.set_knots(vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]).is_ok());
+
+        let message = KnotError::Decreasing { index: 5 }.to_string();
+        assert_eq!(message, "knot 5 is smaller than the knot before it");
+    }
+
+    #[test]
+    fn test_reverse() {
+        let original = curve_on_domain(2.0, 5.0);
+        let mut curve = original.clone();
+        curve.reverse();
+
+        assert_eq!(&curve.domain().unwrap()[..], &[2.0, 5.0]);
+        for i in 0..=20 {
+            let u = 2.0 + 3.0 * i as f64 / 20.0;
+            let a = original.evaluate(u).unwrap();
+            let b = curve.evaluate(7.0 - u).unwrap();
+            assert!((a.x() - b.x()).abs() < 1e-12);
+            assert!((a.y() - b.y()).abs() < 1e-12);
+        }
+
+        curve.reverse();
+        assert_eq!(curve.get_knots(), original.get_knots());
+    }
+
+    #[test]
+    fn test_reparameterize() {
+        let original = weighted_cubic();
+        let mut curve = weighted_cubic();
+
+        assert!(curve.reparameterize(10.0, 13.7).is_ok());
+        assert_eq!(&curve.domain().unwrap()[..], &[10.0, 13.7]);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            let a = original.evaluate(t).unwrap();
+            let b = curve.evaluate(10.0 + 3.7 * t).unwrap();
+            assert!((a.x() - b.x()).abs() < 1e-9);
+            assert!((a.y() - b.y()).abs() < 1e-9);
+        }
+
+        assert!(curve.reparameterize(1.0, 1.0).is_err());
+        assert!(curve.reparameterize(f64::NAN, 1.0).is_err());
+        assert!(NurbsCurve::new(3).reparameterize(0.0, 1.0).is_err());
+    }
 }
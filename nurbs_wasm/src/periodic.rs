@@ -0,0 +1,305 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core;
+use crate::{ControlPoint, NurbsCurve, NurbsError};
+
+// End points closer than this, relative to the size of the curve, count as closed
+const CLOSED_TOLERANCE: f64 = 1e-9;
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Switch between an open clamped curve and a smooth periodic loop
+    // A periodic curve repeats its first `degree` control points at the end and uses a uniform
+    // unclamped knot vector on [0, 1], so it joins with full continuity at the seam.
+    // Turning periodic mode off drops the repeated points and opens the loop.
+    // Either way the knots are replaced, Locked knots go back to uniform clamped generation
+    // Fails if the curve has fewer than two distinct control points or degree 0
+    pub fn set_periodic(&mut self, periodic: bool) -> Result<(), NurbsError> {
+        if periodic == self.periodic {
+            return Ok(());
+        }
+
+        if !periodic {
+            let count = self.distinct_control_points();
+            self.control_points.truncate(count);
+            self.periodic = false;
+            self.unlock_knots();
+            self.update_knots();
+            return Ok(());
+        }
+
+        // A closing point that repeats the first one is part of the seam, not of the loop
+        let mut points = self.control_points.clone();
+        if points.len() > 2 && self.is_closed() {
+            let (first, last) = (&points[0], &points[points.len() - 1]);
+            if first.x == last.x && first.y == last.y && first.weight == last.weight {
+                points.pop();
+            }
+        }
+
+        if points.is_empty() {
+            return Err(NurbsError::EmptyCurve);
+        }
+        if self.degree == 0 || points.len() < 2 {
+            return Err(NurbsError::InvalidDegree {
+                degree: self.degree,
+                num_points: points.len(),
+            });
+        }
+
+        self.wrap_periodic(points);
+        Ok(())
+    }
+
+    // Whether the curve is a periodic loop with wrapped control points
+    pub fn is_periodic(&self) -> bool {
+        self.periodic
+    }
+
+    // Whether the curve ends where it starts
+    pub fn is_closed(&self) -> bool {
+        let (start, end) = match self.parameter_range() {
+            Some(range) => range,
+            None => return false,
+        };
+
+        match (self.evaluate(start), self.evaluate(end)) {
+            (Ok(a), Ok(b)) => {
+                let scale = 1.0 + self.coordinate_scale();
+                (a.x - b.x).hypot(a.y - b.y) <= CLOSED_TOLERANCE * scale
+            }
+            _ => false,
+        }
+    }
+
+    // Close the curve by appending a copy of its first control point
+    // The clamped ends then meet in a corner, use set_periodic for a smooth join
+    // Fails, leaving the curve unchanged, if it has too few control points or its knots keep the
+    // new end away from the start
+    pub fn close(&mut self) -> Result<(), NurbsError> {
+        if self.is_closed() {
+            return Ok(());
+        }
+        self.checked_range()?;
+        if self.control_points.len() < 2 {
+            return Err(NurbsError::InvalidArgument(
+                "a curve needs at least two control points to be closed",
+            ));
+        }
+
+        let mut closed = self.clone();
+        closed.add_control_point(self.control_points[0].clone());
+        let (start, end) = closed.checked_range()?;
+        closed.evaluate(start)?;
+        closed.evaluate(end)?;
+        if !closed.is_closed() {
+            return Err(NurbsError::InvalidArgument(
+                "the knots do not clamp the ends, use set_periodic for a closed loop",
+            ));
+        }
+
+        *self = closed;
+        Ok(())
+    }
+}
+
+impl NurbsCurve {
+    // Number of control points before the wrapped copies of a periodic curve
+    pub(crate) fn distinct_control_points(&self) -> usize {
+        if self.periodic {
+            self.control_points.len().saturating_sub(self.degree)
+        } else {
+            self.control_points.len()
+        }
+    }
+
+    // Make the curve a periodic loop through the given distinct control points
+    pub(crate) fn wrap_periodic(&mut self, mut points: Vec<ControlPoint>) {
+        let count = points.len();
+        for i in 0..self.degree {
+            points.push(points[i % count].clone());
+        }
+
+        self.knots = core::uniform_unclamped_knots(points.len(), self.degree);
+        self.control_points = points;
+        self.periodic = true;
+        self.unlock_knots();
+    }
+
+    // Copy control point `index` onto every wrapped copy of it
+    pub(crate) fn sync_wrapped_points(&mut self, index: usize) {
+        let count = self.distinct_control_points();
+        if count == 0 {
+            return;
+        }
+
+        let point = self.control_points[index].clone();
+        for i in (index % count..self.control_points.len()).step_by(count) {
+            self.control_points[i] = point.clone();
+        }
+    }
+
+    // Map a parameter of a periodic curve into its domain, other curves keep it unchanged
+    pub(crate) fn wrap_parameter(&self, u: f64) -> f64 {
+        if !self.periodic || !u.is_finite() {
+            return u;
+        }
+
+        match self.parameter_range() {
+            Some((start, end)) if end > start && (u < start || u > end) => {
+                // Rounding must not carry the wrapped parameter past the end
+                (start + (u - start).rem_euclid(end - start)).min(end)
+            }
+            _ => u,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::{ControlPoint, KnotStrategy, NurbsCurve};
+
+    fn square(degree: usize) -> NurbsCurve {
+        let mut curve = NurbsCurve::new(degree);
+        for &(x, y) in &[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)] {
+            curve.add_control_point(ControlPoint::new(x, y, 1.0));
+        }
+        curve
+    }
+
+    #[test]
+    fn test_periodic_curve_joins_smoothly() {
+        let mut curve = square(3);
+        assert!(!curve.is_closed());
+        assert!(curve.set_periodic(true).is_ok());
+        assert!(curve.is_periodic());
+        assert!(curve.is_closed());
+        assert_eq!(curve.num_control_points(), 7);
+        assert_eq!(curve.domain().unwrap().to_vec(), vec![0.0, 1.0]);
+
+        // Position, tangent and curvature vector all match across the seam
+        let start = curve.derivatives(0.0, 2).unwrap();
+        let end = curve.derivatives(1.0, 2).unwrap();
+        for (a, b) in start.iter().zip(end.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        // The symmetric square gives a symmetric loop
+        let middle = curve.evaluate(0.5).unwrap();
+        let first = curve.evaluate(0.0).unwrap();
+        assert!((middle.x() - (4.0 - first.x())).abs() < 1e-12);
+        assert!((middle.y() - (4.0 - first.y())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_periodic_parameters_wrap() {
+        let mut curve = square(2);
+        curve.set_periodic(true).unwrap();
+
+        for &u in &[0.1, 0.35, 0.8] {
+            let a = curve.evaluate(u).unwrap();
+            let b = curve.evaluate(u + 1.0).unwrap();
+            let c = curve.evaluate(u - 2.0).unwrap();
+            assert!((a.x() - b.x()).abs() < 1e-12 && (a.y() - b.y()).abs() < 1e-12);
+            assert!((a.x() - c.x()).abs() < 1e-12 && (a.y() - c.y()).abs() < 1e-12);
+        }
+
+        let points = curve.generate_points(9).unwrap();
+        let (first, last) = (&points[0], &points[8]);
+        assert!((first.x() - last.x()).abs() < 1e-12 && (first.y() - last.y()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_periodic_editing() {
+        let mut curve = square(3);
+        curve.set_periodic(true).unwrap();
+
+        // Adding a point extends the loop instead of the wrapped tail
+        curve.add_control_point(ControlPoint::new(-2.0, 2.0, 1.0));
+        assert!(curve.is_periodic());
+        assert_eq!(curve.num_control_points(), 8);
+        assert_eq!(curve.get_control_point(4).unwrap().x(), -2.0);
+        assert_eq!(curve.get_control_point(5).unwrap().x(), 0.0);
+
+        // Moving a point moves its wrapped copy
+        assert!(curve.update_control_point(1, 5.0, -1.0, 1.0).is_ok());
+        let copy = curve.get_control_point(6).unwrap();
+        assert_eq!((copy.x(), copy.y()), (5.0, -1.0));
+        assert!(curve.update_control_point(7, 3.0, 3.0, 1.0).is_ok());
+        assert_eq!(curve.get_control_point(2).unwrap().x(), 3.0);
+        assert!(curve.is_closed());
+
+        // Turning periodic mode off keeps the distinct points as an open curve
+        assert!(curve.set_periodic(false).is_ok());
+        assert!(!curve.is_periodic());
+        assert_eq!(curve.num_control_points(), 5);
+        assert!(!curve.is_closed());
+    }
+
+    #[test]
+    fn test_close() {
+        let mut curve = square(3);
+        assert!(curve.close().is_ok());
+        assert!(curve.is_closed());
+        assert!(!curve.is_periodic());
+        assert_eq!(curve.num_control_points(), 5);
+
+        // Closing again changes nothing, and a closed curve turns into the same smooth loop
+        assert!(curve.close().is_ok());
+        assert_eq!(curve.num_control_points(), 5);
+        assert!(curve.set_periodic(true).is_ok());
+        assert_eq!(curve.num_control_points(), 7);
+
+        assert!(NurbsCurve::new(3).close().is_err());
+        assert!(NurbsCurve::new(3).set_periodic(true).is_err());
+    }
+
+    #[test]
+    fn test_locked_knots_survive_a_periodic_round_trip() {
+        let mut curve = square(3);
+        curve.insert_knot(0.3, 1).unwrap();
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::Locked);
+
+        curve.set_periodic(true).unwrap();
+        assert_eq!(curve.get_knot_strategy(), KnotStrategy::UniformClamped);
+        curve.set_periodic(false).unwrap();
+
+        // The open curve gets fresh uniform clamped knots for its distinct points
+        assert_eq!(
+            curve.get_knots(),
+            core::uniform_clamped_knots(curve.num_control_points(), 3)
+        );
+        assert!(curve.evaluate(0.5).is_ok());
+        assert!(curve.generate_points(8).is_ok());
+
+        // Locking the knots of a periodic curve does not trap them when it opens up
+        curve.set_periodic(true).unwrap();
+        curve.set_knot_strategy(KnotStrategy::Locked);
+        curve.set_periodic(false).unwrap();
+        assert!(curve.evaluate(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_structural_edits_keep_shape_of_periodic_curve() {
+        let mut periodic = square(3);
+        periodic.set_periodic(true).unwrap();
+
+        let mut refined = periodic.clone();
+        assert_eq!(refined.insert_knot(0.3, 1), Ok(1));
+        assert!(!refined.is_periodic());
+
+        let mut elevated = periodic.clone();
+        assert!(elevated.elevate_degree(1).is_ok());
+
+        for i in 0..=20 {
+            let u = i as f64 / 20.0;
+            let expected = periodic.evaluate(u).unwrap();
+            for curve in [&refined, &elevated] {
+                let actual = curve.evaluate(u).unwrap();
+                assert!((expected.x() - actual.x()).abs() < 1e-9);
+                assert!((expected.y() - actual.y()).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{NurbsCurve, NurbsError};
+
+// Maximum number of Newton steps when refining a projection
+const MAX_NEWTON_STEPS: usize = 32;
+
+// Parameter steps below this fraction of the span width stop the refinement
+const PARAMETER_TOLERANCE: f64 = 1e-14;
+
+// Result of projecting a point onto a curve
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ClosestPoint {
+    u: f64,
+    x: f64,
+    y: f64,
+    distance: f64,
+}
+
+#[wasm_bindgen]
+impl ClosestPoint {
+    // Parameter of the closest point on the curve
+    #[wasm_bindgen(getter)]
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    // Euclidean distance from the query point to the curve
+    #[wasm_bindgen(getter)]
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+}
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Find the point on the curve closest to (x, y)
+    // Each knot span is searched coarsely, then refined with Newton iteration on the squared distance
+    pub fn closest_point(&self, x: f64, y: f64) -> Result<ClosestPoint, NurbsError> {
+        let (domain_start, _) = self.checked_range()?;
+        if !x.is_finite() || !y.is_finite() {
+            return Err(NurbsError::InvalidArgument(
+                "the point must have finite coordinates",
+            ));
+        }
+
+        // More samples per span for higher degrees, which can wiggle more within a span
+        let samples_per_span = (2 * self.effective_degree() + 2).max(4);
+
+        let mut best: Option<ClosestPoint> = None;
+        for (a, b) in self.knot_spans() {
+            // Coarse search inside the span, including both ends so clamped endpoints and kinks are candidates
+            let mut start = a;
+            let mut start_distance = f64::INFINITY;
+            for i in 0..=samples_per_span {
+                // Rounding must not push the last sample past the end of the domain
+                let u = (a + (b - a) * i as f64 / samples_per_span as f64).min(b);
+                if let Ok(point) = self.evaluate(u) {
+                    let d = (point.x - x).powi(2) + (point.y - y).powi(2);
+                    if d < start_distance {
+                        start = u;
+                        start_distance = d;
+                    }
+                }
+            }
+
+            if !start_distance.is_finite() {
+                continue;
+            }
+
+            let candidate = self.refine_projection(x, y, start, a, b);
+            if best
+                .as_ref()
+                .is_none_or(|best| candidate.distance < best.distance)
+            {
+                best = Some(candidate);
+            }
+        }
+
+        // Only a curve whose weights vanish everywhere has no point to offer
+        best.ok_or(NurbsError::ZeroWeight { u: domain_start })
+    }
+}
+
+impl NurbsCurve {
+    // Newton iteration on f(u) = C'(u) . (C(u) - P), kept inside the span [a, b]
+    fn refine_projection(&self, x: f64, y: f64, start: f64, a: f64, b: f64) -> ClosestPoint {
+        let mut u = start;
+
+        for _ in 0..MAX_NEWTON_STEPS {
+            let ders = match self.rational_derivatives(u, 2) {
+                Ok(ders) => ders,
+                Err(_) => break,
+            };
+
+            let dx = ders[0][0] - x;
+            let dy = ders[0][1] - y;
+            let f = ders[1][0] * dx + ders[1][1] * dy;
+            let df = ders[2][0] * dx
+                + ders[2][1] * dy
+                + ders[1][0] * ders[1][0]
+                + ders[1][1] * ders[1][1];
+
+            // A non-positive second derivative means Newton would head for a maximum
+            if df <= 0.0 {
+                break;
+            }
+
+            let next = (u - f / df).clamp(a, b);
+            let converged = (next - u).abs() <= PARAMETER_TOLERANCE * (b - a).max(1.0);
+            u = next;
+            if converged {
+                break;
+            }
+        }
+
+        // Keep the coarse sample if refinement wandered to a worse point
+        let refined = self.projection_at(x, y, u);
+        let coarse = self.projection_at(x, y, start);
+        if refined.distance <= coarse.distance {
+            refined
+        } else {
+            coarse
+        }
+    }
+
+    fn projection_at(&self, x: f64, y: f64, u: f64) -> ClosestPoint {
+        match self.evaluate(u) {
+            Ok(point) => ClosestPoint {
+                u,
+                x: point.x,
+                y: point.y,
+                distance: ((point.x - x).powi(2) + (point.y - y).powi(2)).sqrt(),
+            },
+            Err(_) => ClosestPoint {
+                u,
+                x: f64::NAN,
+                y: f64::NAN,
+                distance: f64::INFINITY,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve};
+
+    #[test]
+    fn test_closest_point_on_line() {
+        let mut curve = NurbsCurve::new(1);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+
+        let result = curve.closest_point(3.0, 4.0).unwrap();
+        assert!((result.u() - 0.3).abs() < 1e-12);
+        assert!((result.x() - 3.0).abs() < 1e-12);
+        assert!(result.y().abs() < 1e-12);
+        assert!((result.distance() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_point_on_circle() {
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(1.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(1.0, 1.0, std::f64::consts::FRAC_1_SQRT_2));
+        curve.add_control_point(ControlPoint::new(0.0, 1.0, 1.0));
+
+        // Any point outside the arc projects radially
+        let angle: f64 = 0.3;
+        let result = curve
+            .closest_point(3.0 * angle.cos(), 3.0 * angle.sin())
+            .unwrap();
+        assert!((result.x() - angle.cos()).abs() < 1e-10);
+        assert!((result.y() - angle.sin()).abs() < 1e-10);
+        assert!((result.distance() - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_closest_point_at_clamped_end() {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(1.0, 2.0, 1.0));
+        curve.add_control_point(ControlPoint::new(3.0, 2.0, 1.0));
+        curve.add_control_point(ControlPoint::new(4.0, 0.0, 1.0));
+
+        // Beyond the end the closest point is the endpoint itself
+        let result = curve.closest_point(6.0, -1.0).unwrap();
+        assert_eq!(result.u(), 1.0);
+        assert!((result.x() - 4.0).abs() < 1e-12);
+        assert!(result.y().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_point_at_kink() {
+        // A full-multiplicity interior knot creates a sharp corner at (5, 5)
+        let mut curve = NurbsCurve::new(2);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.5, 2.5, 1.0));
+        curve.add_control_point(ControlPoint::new(5.0, 5.0, 1.0));
+        curve.add_control_point(ControlPoint::new(7.5, 2.5, 1.0));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        curve
+            .set_knots(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0])
+            .unwrap();
+
+        let result = curve.closest_point(5.0, 8.0).unwrap();
+        assert!((result.u() - 0.5).abs() < 1e-12);
+        assert!((result.x() - 5.0).abs() < 1e-12);
+        assert!((result.y() - 5.0).abs() < 1e-12);
+        assert!((result.distance() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closest_point_on_empty_curve() {
+        let curve = NurbsCurve::new(3);
+        assert!(curve.closest_point(0.0, 0.0).is_err());
+    }
+}
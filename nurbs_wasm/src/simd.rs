@@ -0,0 +1,240 @@
+// Two-lane f64 vector used by the numeric kernels
+// Every backend offers the same operations and only performs lane-wise IEEE add, sub, mul and
+// div, never fused or reassociated, so all backends give bit-identical results.
+// - wasm32 with simd128: v128
+// - x86_64: SSE2 __m128d. There is no separate AVX backend, the 256-bit registers would need a
+//   four-lane type that the kernels do not use
+// - anything else: a plain array
+use std::ops::{Add, Div, Mul, Sub};
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub use wasm32::F64x2;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+pub use x86_64::F64x2;
+
+#[cfg(not(any(
+    all(target_arch = "wasm32", target_feature = "simd128"),
+    all(target_arch = "x86_64", target_feature = "sse2")
+)))]
+pub use scalar::F64x2;
+
+impl F64x2 {
+    // Sum of both lanes, lane 0 first
+    #[inline]
+    pub fn sum(self) -> f64 {
+        let [a, b] = self.to_array();
+        a + b
+    }
+}
+
+// Lane-wise operators, implemented by each backend on its own vector type
+macro_rules! lane_ops {
+    ($vector:ty, $add:expr, $sub:expr, $mul:expr, $div:expr) => {
+        impl Add for $vector {
+            type Output = $vector;
+
+            #[inline]
+            fn add(self, rhs: $vector) -> $vector {
+                $add(self, rhs)
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+
+            #[inline]
+            fn sub(self, rhs: $vector) -> $vector {
+                $sub(self, rhs)
+            }
+        }
+
+        impl Mul for $vector {
+            type Output = $vector;
+
+            #[inline]
+            fn mul(self, rhs: $vector) -> $vector {
+                $mul(self, rhs)
+            }
+        }
+
+        impl Div for $vector {
+            type Output = $vector;
+
+            #[inline]
+            fn div(self, rhs: $vector) -> $vector {
+                $div(self, rhs)
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    test,
+    not(any(
+        all(target_arch = "wasm32", target_feature = "simd128"),
+        all(target_arch = "x86_64", target_feature = "sse2")
+    ))
+))]
+mod scalar {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct F64x2([f64; 2]);
+
+    impl F64x2 {
+        #[inline]
+        pub fn new(a: f64, b: f64) -> F64x2 {
+            F64x2([a, b])
+        }
+
+        #[inline]
+        pub fn splat(a: f64) -> F64x2 {
+            F64x2([a, a])
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f64; 2] {
+            self.0
+        }
+    }
+
+    lane_ops!(
+        F64x2,
+        |a: F64x2, b: F64x2| F64x2([a.0[0] + b.0[0], a.0[1] + b.0[1]]),
+        |a: F64x2, b: F64x2| F64x2([a.0[0] - b.0[0], a.0[1] - b.0[1]]),
+        |a: F64x2, b: F64x2| F64x2([a.0[0] * b.0[0], a.0[1] * b.0[1]]),
+        |a: F64x2, b: F64x2| F64x2([a.0[0] / b.0[0], a.0[1] / b.0[1]])
+    );
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm32 {
+    use super::*;
+    use std::arch::wasm32::*;
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct F64x2(v128);
+
+    impl F64x2 {
+        #[inline]
+        pub fn new(a: f64, b: f64) -> F64x2 {
+            F64x2(f64x2(a, b))
+        }
+
+        #[inline]
+        pub fn splat(a: f64) -> F64x2 {
+            F64x2(f64x2_splat(a))
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f64; 2] {
+            [
+                f64x2_extract_lane::<0>(self.0),
+                f64x2_extract_lane::<1>(self.0),
+            ]
+        }
+    }
+
+    lane_ops!(
+        F64x2,
+        |a: F64x2, b: F64x2| F64x2(f64x2_add(a.0, b.0)),
+        |a: F64x2, b: F64x2| F64x2(f64x2_sub(a.0, b.0)),
+        |a: F64x2, b: F64x2| F64x2(f64x2_mul(a.0, b.0)),
+        |a: F64x2, b: F64x2| F64x2(f64x2_div(a.0, b.0))
+    );
+}
+
+// SAFETY (whole module): the module only exists when the build enables sse2, which every
+// x86_64 target does, so the SSE2 intrinsics are always available
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod x86_64 {
+    use super::*;
+    use std::arch::x86_64::*;
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct F64x2(__m128d);
+
+    impl F64x2 {
+        #[inline]
+        pub fn new(a: f64, b: f64) -> F64x2 {
+            // _mm_set_pd takes the high lane first
+            F64x2(unsafe { _mm_set_pd(b, a) })
+        }
+
+        #[inline]
+        pub fn splat(a: f64) -> F64x2 {
+            F64x2(unsafe { _mm_set1_pd(a) })
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f64; 2] {
+            let mut lanes = [0.0; 2];
+            unsafe { _mm_storeu_pd(lanes.as_mut_ptr(), self.0) };
+            lanes
+        }
+    }
+
+    lane_ops!(
+        F64x2,
+        |a: F64x2, b: F64x2| F64x2(unsafe { _mm_add_pd(a.0, b.0) }),
+        |a: F64x2, b: F64x2| F64x2(unsafe { _mm_sub_pd(a.0, b.0) }),
+        |a: F64x2, b: F64x2| F64x2(unsafe { _mm_mul_pd(a.0, b.0) }),
+        |a: F64x2, b: F64x2| F64x2(unsafe { _mm_div_pd(a.0, b.0) })
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scalar, F64x2};
+
+    // Awkward values: signed zeros, subnormals, infinities, NaN and values that round
+    const VALUES: [f64; 12] = [
+        0.0,
+        -0.0,
+        1.0,
+        -2.5,
+        0.1,
+        1.0 / 3.0,
+        1e-310,
+        1e300,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    // Equal bits, except that NaN payloads may differ between instruction sets
+    fn same(actual: F64x2, expected: scalar::F64x2) -> bool {
+        let expected = expected.to_array();
+        actual
+            .to_array()
+            .iter()
+            .zip(expected)
+            .all(|(a, e)| a.to_bits() == e.to_bits() || (a.is_nan() && e.is_nan()))
+    }
+
+    #[test]
+    fn test_backend_matches_scalar_bit_for_bit() {
+        for &a in &VALUES {
+            for &b in &VALUES {
+                let (x, y) = (F64x2::new(a, b), F64x2::new(b, -a));
+                let (sx, sy) = (scalar::F64x2::new(a, b), scalar::F64x2::new(b, -a));
+
+                assert!(same(x + y, sx + sy));
+                assert!(same(x - y, sx - sy));
+                assert!(same(x * y, sx * sy));
+                assert!(same(x / y, sx / sy));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lanes_keep_their_order() {
+        let v = F64x2::new(1.0, 2.0);
+        assert_eq!(v.to_array(), [1.0, 2.0]);
+        assert_eq!(F64x2::splat(3.0).to_array(), [3.0, 3.0]);
+        assert_eq!(scalar::F64x2::splat(3.0).to_array(), [3.0, 3.0]);
+        assert_eq!((v * F64x2::new(3.0, 0.5)).sum(), 4.0);
+    }
+}
@@ -0,0 +1,152 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{NurbsCurve, NurbsError};
+
+#[wasm_bindgen]
+impl NurbsCurve {
+    // Split the curve at parameter u into two curves, returned as a two-element array
+    // Fails if u is not strictly inside the curve domain
+    pub fn split(&self, u: f64) -> Result<Vec<NurbsCurve>, NurbsError> {
+        let (left, right) = self.split_at(u)?;
+        Ok(vec![left, right])
+    }
+
+    // Extract the part of the curve between parameters u0 and u1
+    // The sub-curve keeps the original parameterization over [u0, u1]
+    pub fn subcurve(&self, u0: f64, u1: f64) -> Result<NurbsCurve, NurbsError> {
+        let (start, end) = self.checked_range()?;
+        if !u0.is_finite() || !u1.is_finite() {
+            return Err(NurbsError::InvalidArgument("the range must be finite"));
+        }
+
+        let (u0, u1) = (u0.min(u1).max(start), u0.max(u1).min(end));
+        if u1 <= u0 {
+            return Err(NurbsError::InvalidArgument(
+                "the range must overlap the curve domain",
+            ));
+        }
+
+        let mut curve = self.clone();
+        if u0 > start {
+            curve = curve.split_at(u0)?.1;
+        }
+        if u1 < end {
+            curve = curve.split_at(u1)?.0;
+        }
+
+        Ok(curve)
+    }
+}
+
+impl NurbsCurve {
+    // Split the curve at parameter u by inserting u up to full multiplicity
+    // The left curve covers [start, u] and the right curve covers [u, end]
+    pub fn split_at(&self, u: f64) -> Result<(NurbsCurve, NurbsCurve), NurbsError> {
+        let p = self.effective_degree();
+        let mut refined = self.clone();
+        let existing = refined.knot_multiplicity(u);
+        refined.insert_knot(u, p.saturating_sub(existing))?;
+
+        // The control point at index k - p lies on the curve and is shared by both halves
+        let k = refined.knots.partition_point(|&knot| knot <= u) - 1;
+
+        let mut left_knots = refined.knots[..=k].to_vec();
+        left_knots.push(u);
+        let left = NurbsCurve::from_parts(refined.control_points[..=k - p].to_vec(), left_knots, p);
+
+        let mut right_knots = vec![u];
+        right_knots.extend_from_slice(&refined.knots[k - p + 1..]);
+        let right =
+            NurbsCurve::from_parts(refined.control_points[k - p..].to_vec(), right_knots, p);
+
+        Ok((left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint, NurbsCurve};
+
+    fn weighted_cubic() -> NurbsCurve {
+        let mut curve = NurbsCurve::new(3);
+        curve.add_control_point(ControlPoint::new(0.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(2.0, 5.0, 2.0));
+        curve.add_control_point(ControlPoint::new(5.0, -1.0, 0.5));
+        curve.add_control_point(ControlPoint::new(8.0, 4.0, 1.5));
+        curve.add_control_point(ControlPoint::new(10.0, 0.0, 1.0));
+        curve.add_control_point(ControlPoint::new(12.0, 3.0, 0.8));
+        curve
+    }
+
+    fn assert_matches(original: &NurbsCurve, part: &NurbsCurve, u0: f64, u1: f64) {
+        for i in 0..=50 {
+            let u = u0 + (u1 - u0) * i as f64 / 50.0;
+            let expected = original.evaluate(u).unwrap();
+            let actual = part.evaluate(u).unwrap();
+            assert!(
+                (expected.x() - actual.x()).abs() < 1e-12,
+                "x differs at u = {}",
+                u
+            );
+            assert!(
+                (expected.y() - actual.y()).abs() < 1e-12,
+                "y differs at u = {}",
+                u
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_at() {
+        let curve = weighted_cubic();
+        let (left, right) = curve.split_at(0.4).unwrap();
+
+        assert_eq!(left.get_degree(), 3);
+        assert_eq!(right.get_degree(), 3);
+        assert_matches(&curve, &left, 0.0, 0.4);
+        assert_matches(&curve, &right, 0.4, 1.0);
+
+        // Both halves meet at the split point
+        let end = left
+            .get_control_point(left.num_control_points() - 1)
+            .unwrap();
+        let start = right.get_control_point(0).unwrap();
+        assert_eq!((end.x(), end.y()), (start.x(), start.y()));
+    }
+
+    #[test]
+    fn test_split_at_existing_knot() {
+        let curve = weighted_cubic();
+        let (left, right) = curve.split_at(1.0 / 3.0).unwrap();
+        assert_matches(&curve, &left, 0.0, 1.0 / 3.0);
+        assert_matches(&curve, &right, 1.0 / 3.0, 1.0);
+
+        assert_eq!(curve.split(0.5).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_split_outside_domain() {
+        let curve = weighted_cubic();
+        assert!(curve.split_at(0.0).is_err());
+        assert!(curve.split_at(1.0).is_err());
+        assert!(curve.split(1.5).is_err());
+        assert!(NurbsCurve::new(3).split_at(0.5).is_err());
+    }
+
+    #[test]
+    fn test_subcurve() {
+        let curve = weighted_cubic();
+
+        let middle = curve.subcurve(0.25, 0.8).unwrap();
+        assert_matches(&curve, &middle, 0.25, 0.8);
+
+        // Reversed and out-of-range bounds are normalized
+        let tail = curve.subcurve(1.5, 0.6).unwrap();
+        assert_matches(&curve, &tail, 0.6, 1.0);
+
+        let whole = curve.subcurve(0.0, 1.0).unwrap();
+        assert_eq!(whole.num_control_points(), curve.num_control_points());
+
+        assert!(curve.subcurve(0.5, 0.5).is_err());
+    }
+}
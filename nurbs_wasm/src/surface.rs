@@ -0,0 +1,468 @@
+use wasm_bindgen::prelude::*;
+
+use crate::core::{self, binomial, WeightedPoint};
+use crate::{ControlPoint3, NurbsError};
+
+// Parameter interval (start, end) of one direction
+type ParameterRange = (f64, f64);
+
+// A tensor-product NURBS surface
+// Control points form a grid stored row by row, index (i, j) runs along u and v respectively
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct NurbsSurface {
+    control_points: Vec<ControlPoint3>,
+    num_u: usize,
+    num_v: usize,
+    knots_u: Vec<f64>,
+    knots_v: Vec<f64>,
+    degree_u: usize,
+    degree_v: usize,
+}
+
+#[wasm_bindgen]
+impl NurbsSurface {
+    // Create a surface with a num_u x num_v grid of control points at the origin
+    // Both directions start with uniform clamped knot vectors
+    #[wasm_bindgen(constructor)]
+    pub fn new(degree_u: usize, degree_v: usize, num_u: usize, num_v: usize) -> NurbsSurface {
+        let mut surface = NurbsSurface {
+            control_points: vec![ControlPoint3::new(0.0, 0.0, 0.0, 1.0); num_u * num_v],
+            num_u,
+            num_v,
+            knots_u: Vec::new(),
+            knots_v: Vec::new(),
+            degree_u,
+            degree_v,
+        };
+        surface.knots_u = core::uniform_clamped_knots(num_u, surface.effective_degree_u());
+        surface.knots_v = core::uniform_clamped_knots(num_v, surface.effective_degree_v());
+        surface
+    }
+
+    // Number of control points along u
+    pub fn num_u(&self) -> usize {
+        self.num_u
+    }
+
+    // Number of control points along v
+    pub fn num_v(&self) -> usize {
+        self.num_v
+    }
+
+    pub fn get_degree_u(&self) -> usize {
+        self.degree_u
+    }
+
+    pub fn get_degree_v(&self) -> usize {
+        self.degree_v
+    }
+
+    // Degree the surface is evaluated with along u, lowered to num_u - 1 for a short grid
+    // as the curves do
+    pub fn effective_degree_u(&self) -> usize {
+        self.degree_u.min(self.num_u.saturating_sub(1))
+    }
+
+    // Degree the surface is evaluated with along v, lowered to num_v - 1 for a short grid
+    pub fn effective_degree_v(&self) -> usize {
+        self.degree_v.min(self.num_v.saturating_sub(1))
+    }
+
+    // Get the control point at grid position (i, j)
+    pub fn get_control_point(&self, i: usize, j: usize) -> Result<ControlPoint3, NurbsError> {
+        let index = self.grid_index(i, j)?;
+        Ok(self.control_points[index].clone())
+    }
+
+    // Replace the control point at grid position (i, j)
+    pub fn set_control_point(
+        &mut self,
+        i: usize,
+        j: usize,
+        control_point: ControlPoint3,
+    ) -> Result<(), NurbsError> {
+        let index = self.grid_index(i, j)?;
+        self.control_points[index] = control_point;
+        Ok(())
+    }
+
+    // Replace all control points from a flat [x, y, z, w, ...] array in row order
+    // Fails if the array does not match the grid size
+    pub fn set_control_points(&mut self, points: Vec<f64>) -> Result<(), NurbsError> {
+        if points.len() != self.num_u * self.num_v * 4 {
+            return Err(NurbsError::InvalidArgument(
+                "the array must hold x, y, z, w for every grid point",
+            ));
+        }
+
+        self.control_points = points
+            .chunks_exact(4)
+            .map(|p| ControlPoint3::new(p[0], p[1], p[2], p[3]))
+            .collect();
+        Ok(())
+    }
+
+    // Set the knot vector in the u direction manually
+    pub fn set_knots_u(&mut self, knots: Vec<f64>) -> Result<(), NurbsError> {
+        core::validate_knots(&knots, self.effective_degree_u(), self.num_u)?;
+        self.knots_u = knots;
+        Ok(())
+    }
+
+    // Set the knot vector in the v direction manually
+    pub fn set_knots_v(&mut self, knots: Vec<f64>) -> Result<(), NurbsError> {
+        core::validate_knots(&knots, self.effective_degree_v(), self.num_v)?;
+        self.knots_v = knots;
+        Ok(())
+    }
+
+    pub fn get_knots_u(&self) -> Vec<f64> {
+        self.knots_u.clone()
+    }
+
+    pub fn get_knots_v(&self) -> Vec<f64> {
+        self.knots_v.clone()
+    }
+
+    // Get the parameter domain as [u_start, u_end, v_start, v_end]
+    pub fn domain(&self) -> Result<Box<[f64]>, NurbsError> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges()?;
+        Ok(Box::new([u0, u1, v0, v1]))
+    }
+
+    // Evaluate the surface at (u, v), returning [x, y, z]
+    pub fn evaluate(&self, u: f64, v: f64) -> Result<Box<[f64]>, NurbsError> {
+        let point = self.point_at(u, v)?;
+        Ok(Box::new(point))
+    }
+
+    // Evaluate the surface point and its partial derivatives up to the given order in each direction
+    // Returns a flat array where entry (k, l), the k-th derivative in u and l-th in v,
+    // starts at index 3 * (k * (order + 1) + l)
+    pub fn derivatives(&self, u: f64, v: f64, order: usize) -> Result<Box<[f64]>, NurbsError> {
+        let ders = self.rational_derivatives(u, v, order)?;
+        Ok(ders.concat().concat().into_boxed_slice())
+    }
+}
+
+impl NurbsSurface {
+    // Index of grid position (i, j) in the row-major control point list
+    // An index out of range is reported against the size of its own direction
+    fn grid_index(&self, i: usize, j: usize) -> Result<usize, NurbsError> {
+        if i >= self.num_u {
+            return Err(NurbsError::IndexOutOfRange {
+                index: i,
+                len: self.num_u,
+            });
+        }
+        if j >= self.num_v {
+            return Err(NurbsError::IndexOutOfRange {
+                index: j,
+                len: self.num_v,
+            });
+        }
+        Ok(i * self.num_v + j)
+    }
+
+    // Domains in u and v, if both directions have enough knots and control points
+    pub(crate) fn parameter_ranges(&self) -> Result<(ParameterRange, ParameterRange), NurbsError> {
+        let range_u = core::check_curve(&self.knots_u, self.effective_degree_u(), self.num_u)?;
+        let range_v = core::check_curve(&self.knots_v, self.effective_degree_v(), self.num_v)?;
+        Ok((range_u, range_v))
+    }
+
+    // Knot spans of (u, v), both parameters must lie in the domain
+    fn spans(&self, u: f64, v: f64) -> Result<(usize, f64, usize, f64), NurbsError> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges()?;
+        core::check_parameter(u, u0, u1)?;
+        core::check_parameter(v, v0, v1)?;
+
+        // Cannot fail once the layouts are checked
+        let span_u = core::find_span(&self.knots_u, self.effective_degree_u(), self.num_u, u)
+            .ok_or(NurbsError::EmptyCurve)?;
+        let span_v = core::find_span(&self.knots_v, self.effective_degree_v(), self.num_v, v)
+            .ok_or(NurbsError::EmptyCurve)?;
+
+        Ok((span_u, u, span_v, v))
+    }
+
+    // Evaluate the surface at (u, v)
+    pub(crate) fn point_at(&self, u: f64, v: f64) -> Result<[f64; 3], NurbsError> {
+        let (span_u, u, span_v, v) = self.spans(u, v)?;
+        let (p, q) = (self.effective_degree_u(), self.effective_degree_v());
+        let basis_u = core::basis_functions(&self.knots_u, p, span_u, u);
+        let basis_v = core::basis_functions(&self.knots_v, q, span_v, v);
+
+        let mut numerator = [0.0; 3];
+        let mut denominator = 0.0;
+        for (k, nu) in basis_u.iter().enumerate() {
+            let row = (span_u - p + k) * self.num_v + span_v - q;
+            for (cp, nv) in self.control_points[row..=row + q]
+                .iter()
+                .zip(basis_v.iter())
+            {
+                let weighted_basis = nu * nv * cp.weight();
+                for (value, coordinate) in numerator.iter_mut().zip(cp.coordinates()) {
+                    *value += weighted_basis * coordinate;
+                }
+                denominator += weighted_basis;
+            }
+        }
+
+        // Avoid division by zero
+        if denominator.abs() < 1e-10 {
+            return Err(NurbsError::SurfaceZeroWeight { u, v });
+        }
+
+        Ok(numerator.map(|value| value / denominator))
+    }
+
+    // Partial derivatives of the rational surface, entry [k][l] is the k-th derivative in u and l-th in v
+    pub(crate) fn rational_derivatives(
+        &self,
+        u: f64,
+        v: f64,
+        order: usize,
+    ) -> Result<Vec<Vec<[f64; 3]>>, NurbsError> {
+        let (span_u, u, span_v, v) = self.spans(u, v)?;
+        let (p, q) = (self.effective_degree_u(), self.effective_degree_v());
+        let ders_u = core::basis_function_derivatives(&self.knots_u, p, span_u, u, order);
+        let ders_v = core::basis_function_derivatives(&self.knots_v, q, span_v, v, order);
+
+        // Derivatives of the homogeneous surface (w*x, w*y, w*z, w)
+        let mut homogeneous = vec![vec![[0.0; 4]; order + 1]; order + 1];
+        for (k, row_ders) in homogeneous.iter_mut().enumerate() {
+            for (l, h) in row_ders.iter_mut().enumerate() {
+                for (r, nu) in ders_u[k].iter().enumerate() {
+                    let row = (span_u - p + r) * self.num_v + span_v - q;
+                    for (cp, nv) in self.control_points[row..=row + q]
+                        .iter()
+                        .zip(ders_v[l].iter())
+                    {
+                        let weighted_basis = nu * nv * cp.weight();
+                        let [x, y, z] = cp.coordinates();
+                        h[0] += weighted_basis * x;
+                        h[1] += weighted_basis * y;
+                        h[2] += weighted_basis * z;
+                        h[3] += weighted_basis;
+                    }
+                }
+            }
+        }
+
+        let w0 = homogeneous[0][0][3];
+        if w0.abs() < 1e-10 {
+            return Err(NurbsError::SurfaceZeroWeight { u, v });
+        }
+
+        // Quotient rule for rational surfaces (The NURBS Book, A4.4)
+        let mut ders = vec![vec![[0.0; 3]; order + 1]; order + 1];
+        for k in 0..=order {
+            for l in 0..=order {
+                let a = homogeneous[k][l];
+                let mut value = [a[0], a[1], a[2]];
+                let mut subtract = |coefficient: f64, previous: [f64; 3]| {
+                    for (v, p) in value.iter_mut().zip(previous) {
+                        *v -= coefficient * p;
+                    }
+                };
+
+                for j in 1..=l {
+                    subtract(binomial(l, j) * homogeneous[0][j][3], ders[k][l - j]);
+                }
+                for i in 1..=k {
+                    subtract(binomial(k, i) * homogeneous[i][0][3], ders[k - i][l]);
+                    for j in 1..=l {
+                        subtract(
+                            binomial(k, i) * binomial(l, j) * homogeneous[i][j][3],
+                            ders[k - i][l - j],
+                        );
+                    }
+                }
+
+                ders[k][l] = value.map(|v| v / w0);
+            }
+        }
+
+        Ok(ders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint3, NurbsError, NurbsSurface};
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    // A quarter cylinder of radius 2 around the z axis, circular in u and straight in v
+    fn quarter_cylinder() -> NurbsSurface {
+        let mut surface = NurbsSurface::new(2, 1, 3, 2);
+        let arc = [(2.0, 0.0, 1.0), (2.0, 2.0, FRAC_1_SQRT_2), (0.0, 2.0, 1.0)];
+        for (i, &(x, y, w)) in arc.iter().enumerate() {
+            for (j, z) in [0.0, 3.0].iter().enumerate() {
+                surface
+                    .set_control_point(i, j, ControlPoint3::new(x, y, *z, w))
+                    .unwrap();
+            }
+        }
+        surface
+    }
+
+    #[test]
+    fn test_bilinear_patch() {
+        let mut surface = NurbsSurface::new(1, 1, 2, 2);
+        assert!(surface
+            .set_control_points(vec![
+                0.0, 0.0, 0.0, 1.0, //
+                0.0, 1.0, 0.0, 1.0, //
+                1.0, 0.0, 0.0, 1.0, //
+                1.0, 1.0, 1.0, 1.0,
+            ])
+            .is_ok());
+        assert!(surface.set_control_points(vec![0.0; 12]).is_err());
+
+        let point = surface.evaluate(0.25, 0.5).unwrap();
+        assert!((point[0] - 0.25).abs() < 1e-12);
+        assert!((point[1] - 0.5).abs() < 1e-12);
+        assert!((point[2] - 0.125).abs() < 1e-12);
+        assert_eq!(surface.domain().unwrap().to_vec(), vec![0.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rational_surface_points() {
+        let surface = quarter_cylinder();
+        for i in 0..=10 {
+            for j in 0..=4 {
+                let (u, v) = (i as f64 / 10.0, j as f64 / 4.0);
+                let point = surface.evaluate(u, v).unwrap();
+                assert!((point[0].hypot(point[1]) - 2.0).abs() < 1e-12);
+                assert!((point[2] - 3.0 * v).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        let mut surface = quarter_cylinder();
+        surface
+            .set_control_point(1, 1, ControlPoint3::new(2.5, 2.0, 3.5, 0.9))
+            .unwrap();
+        let h = 1e-5;
+
+        for &(u, v) in &[(0.2, 0.3), (0.6, 0.7), (0.9, 0.1)] {
+            let ders = surface.derivatives(u, v, 1).unwrap();
+            let entry = |k: usize, l: usize, c: usize| ders[3 * (k * 2 + l) + c];
+
+            let du = |c: usize| {
+                (surface.evaluate(u + h, v).unwrap()[c] - surface.evaluate(u - h, v).unwrap()[c])
+                    / (2.0 * h)
+            };
+            let dv = |c: usize| {
+                (surface.evaluate(u, v + h).unwrap()[c] - surface.evaluate(u, v - h).unwrap()[c])
+                    / (2.0 * h)
+            };
+            let duv = |c: usize| {
+                let at = |du: f64, dv: f64| surface.evaluate(u + du, v + dv).unwrap()[c];
+                (at(h, h) - at(h, -h) - at(-h, h) + at(-h, -h)) / (4.0 * h * h)
+            };
+
+            let point = surface.evaluate(u, v).unwrap();
+            for c in 0..3 {
+                assert!((entry(0, 0, c) - point[c]).abs() < 1e-12);
+                assert!(
+                    (entry(1, 0, c) - du(c)).abs() < 1e-6,
+                    "du at ({}, {})",
+                    u,
+                    v
+                );
+                assert!(
+                    (entry(0, 1, c) - dv(c)).abs() < 1e-6,
+                    "dv at ({}, {})",
+                    u,
+                    v
+                );
+                assert!(
+                    (entry(1, 1, c) - duv(c)).abs() < 1e-4,
+                    "duv at ({}, {})",
+                    u,
+                    v
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_surface() {
+        let surface = NurbsSurface::new(3, 1, 0, 2);
+        assert!(surface.evaluate(0.5, 0.5).is_err());
+        assert!(surface.domain().is_err());
+
+        // Each index is checked against its own direction, however large
+        let mut surface = NurbsSurface::new(1, 1, 2, 3);
+        assert_eq!(
+            surface.get_control_point(2, 0).unwrap_err(),
+            NurbsError::IndexOutOfRange { index: 2, len: 2 }
+        );
+        assert_eq!(
+            surface.get_control_point(1, usize::MAX).unwrap_err(),
+            NurbsError::IndexOutOfRange {
+                index: usize::MAX,
+                len: 3
+            }
+        );
+        assert!(surface
+            .set_control_point(
+                usize::MAX,
+                usize::MAX,
+                ControlPoint3::new(0.0, 0.0, 0.0, 1.0)
+            )
+            .is_err());
+
+        // Weights that cancel out leave the surface without a point there
+        surface
+            .set_control_points(vec![
+                0.0, 0.0, 0.0, 1.0, //
+                0.0, 1.0, 0.0, 1.0, //
+                0.0, 2.0, 0.0, 1.0, //
+                1.0, 0.0, 0.0, -1.0, //
+                1.0, 1.0, 0.0, -1.0, //
+                1.0, 2.0, 0.0, -1.0,
+            ])
+            .unwrap();
+        assert_eq!(
+            surface.evaluate(0.5, 0.25).unwrap_err(),
+            NurbsError::SurfaceZeroWeight { u: 0.5, v: 0.25 }
+        );
+        assert_eq!(
+            surface.derivatives(0.5, 0.75, 1).unwrap_err(),
+            NurbsError::SurfaceZeroWeight { u: 0.5, v: 0.75 }
+        );
+    }
+
+    #[test]
+    fn test_short_grid_lowers_the_degree() {
+        // Two rows along u cannot carry the cubic, the surface evaluates as bilinear
+        let mut surface = NurbsSurface::new(3, 1, 2, 2);
+        assert_eq!(surface.get_degree_u(), 3);
+        assert_eq!(surface.effective_degree_u(), 1);
+        assert_eq!(surface.effective_degree_v(), 1);
+        assert_eq!(surface.get_knots_u(), vec![0.0, 0.0, 1.0, 1.0]);
+        surface
+            .set_control_points(vec![
+                0.0, 0.0, 0.0, 1.0, //
+                0.0, 1.0, 0.0, 1.0, //
+                1.0, 0.0, 0.0, 1.0, //
+                1.0, 1.0, 1.0, 1.0,
+            ])
+            .unwrap();
+
+        let point = surface.evaluate(0.25, 0.5).unwrap();
+        assert_eq!(point.to_vec(), vec![0.25, 0.5, 0.125]);
+        let ders = surface.derivatives(0.25, 0.5, 1).unwrap();
+        assert_eq!(ders[3..6].to_vec(), vec![0.0, 1.0, 0.25]);
+        assert!(surface
+            .set_knots_u(vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0])
+            .is_err());
+        assert!(surface.evaluate(0.5, 1.5).is_err());
+    }
+}
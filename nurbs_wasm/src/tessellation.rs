@@ -0,0 +1,464 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{NurbsError, NurbsSurface};
+
+// Cross products shorter than this are treated as degenerate points such as poles
+const MIN_NORMAL_LENGTH: f64 = 1e-12;
+
+// Fraction of the domain used to step away from a degenerate point when estimating its normal
+const NORMAL_OFFSET: f64 = 1e-6;
+
+// Most vertices a tessellation may have, about 56 MB of mesh buffers
+const MAX_MESH_VERTICES: usize = 1 << 20;
+
+// Indexed triangle mesh of a tessellated surface, laid out as flat buffers for WebGL
+// The *_ptr methods point into wasm memory so JS can create typed array views without copying,
+// views must be recreated if the wasm memory grows and must not outlive the mesh
+#[wasm_bindgen]
+pub struct SurfaceMesh {
+    positions: Vec<f32>,
+    normals: Vec<f32>,
+    uvs: Vec<f32>,
+    indices: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl SurfaceMesh {
+    // Vertex positions as [x1, y1, z1, x2, y2, z2, ...]
+    #[wasm_bindgen(getter)]
+    pub fn positions(&self) -> Box<[f32]> {
+        self.positions.clone().into_boxed_slice()
+    }
+
+    // Unit vertex normals as [nx1, ny1, nz1, ...], zero where no normal is defined
+    #[wasm_bindgen(getter)]
+    pub fn normals(&self) -> Box<[f32]> {
+        self.normals.clone().into_boxed_slice()
+    }
+
+    // Texture coordinates as [s1, t1, s2, t2, ...], the surface parameters normalized to [0, 1]
+    #[wasm_bindgen(getter)]
+    pub fn uvs(&self) -> Box<[f32]> {
+        self.uvs.clone().into_boxed_slice()
+    }
+
+    // Triangle vertex indices, counter-clockwise when viewed against the normal
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Box<[u32]> {
+        self.indices.clone().into_boxed_slice()
+    }
+
+    pub fn positions_ptr(&self) -> *const f32 {
+        self.positions.as_ptr()
+    }
+
+    pub fn normals_ptr(&self) -> *const f32 {
+        self.normals.as_ptr()
+    }
+
+    pub fn uvs_ptr(&self) -> *const f32 {
+        self.uvs.as_ptr()
+    }
+
+    pub fn indices_ptr(&self) -> *const u32 {
+        self.indices.as_ptr()
+    }
+
+    // Number of vertices, each with three position, three normal and two uv floats
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / 3
+    }
+
+    // Number of indices, three per triangle
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+#[wasm_bindgen]
+impl NurbsSurface {
+    // Tessellate the surface on a uniform grid of segments_u x segments_v cells
+    // Fails if the surface cannot be evaluated or the grid would exceed MAX_MESH_VERTICES vertices
+    pub fn tessellate_uniform(
+        &self,
+        segments_u: usize,
+        segments_v: usize,
+    ) -> Result<SurfaceMesh, NurbsError> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges()?;
+        let vertices = (segments_u.max(1).saturating_add(1))
+            .saturating_mul(segments_v.max(1).saturating_add(1));
+        if vertices > MAX_MESH_VERTICES {
+            return Err(NurbsError::InvalidArgument(
+                "the tessellation has too many segments",
+            ));
+        }
+
+        let us = uniform_parameters(u0, u1, segments_u.max(1));
+        let vs = uniform_parameters(v0, v1, segments_v.max(1));
+        self.build_mesh(&us, &vs)
+    }
+
+    // Tessellate the surface so no cell deviates from the surface by more than `tolerance`
+    // Starts from the knot spans and bisects grid lines where the chordal deviation is too large,
+    // at most `max_depth` times. The grid is shared by all rows and columns so the mesh has no cracks.
+    // Refinement also stops before the mesh would exceed MAX_MESH_VERTICES (1 << 20) vertices,
+    // so a tiny tolerance or large depth cannot exhaust memory but may leave the tolerance unmet
+    // Fails if the surface cannot be evaluated or the tolerance is not a positive number
+    pub fn tessellate_adaptive(
+        &self,
+        tolerance: f64,
+        max_depth: usize,
+    ) -> Result<SurfaceMesh, NurbsError> {
+        let (us, vs) = self.adaptive_grid(tolerance, max_depth, MAX_MESH_VERTICES)?;
+        self.build_mesh(&us, &vs)
+    }
+}
+
+impl NurbsSurface {
+    // Grid lines of the adaptive tessellation, with at most max_vertices grid points
+    fn adaptive_grid(
+        &self,
+        tolerance: f64,
+        max_depth: usize,
+        max_vertices: usize,
+    ) -> Result<(Vec<f64>, Vec<f64>), NurbsError> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges()?;
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(NurbsError::InvalidArgument(
+                "the tolerance must be finite and positive",
+            ));
+        }
+
+        let mut us = distinct_knots(&self.get_knots_u(), u0, u1);
+        let mut vs = distinct_knots(&self.get_knots_v(), v0, v1);
+
+        for _ in 0..max_depth {
+            let mut split_u = vec![false; us.len() - 1];
+            let mut split_v = vec![false; vs.len() - 1];
+
+            for (i, u) in us.windows(2).enumerate() {
+                for (j, v) in vs.windows(2).enumerate() {
+                    let corners = [
+                        self.point(u[0], v[0]),
+                        self.point(u[1], v[0]),
+                        self.point(u[0], v[1]),
+                        self.point(u[1], v[1]),
+                    ];
+                    let (um, vm) = (0.5 * (u[0] + u[1]), 0.5 * (v[0] + v[1]));
+
+                    // Edges along u and v decide each direction on its own
+                    let along_u = deviation(self.point(um, v[0]), &[corners[0], corners[1]])
+                        .max(deviation(self.point(um, v[1]), &[corners[2], corners[3]]));
+                    let along_v = deviation(self.point(u[0], vm), &[corners[0], corners[2]])
+                        .max(deviation(self.point(u[1], vm), &[corners[1], corners[3]]));
+                    split_u[i] |= along_u > tolerance;
+                    split_v[j] |= along_v > tolerance;
+
+                    // A twisted cell with straight edges bulges in the middle, split it both ways
+                    if along_u <= tolerance
+                        && along_v <= tolerance
+                        && deviation(self.point(um, vm), &corners) > tolerance
+                    {
+                        split_u[i] = true;
+                        split_v[j] = true;
+                    }
+                }
+            }
+
+            let refined_u = bisect(&us, &split_u);
+            let refined_v = bisect(&vs, &split_v);
+            if refined_u.len() == us.len() && refined_v.len() == vs.len()
+                || refined_u.len() * refined_v.len() > max_vertices
+            {
+                break;
+            }
+            us = refined_u;
+            vs = refined_v;
+        }
+
+        Ok((us, vs))
+    }
+
+    // Surface point for deviation checks, NaN where the surface cannot be evaluated
+    fn point(&self, u: f64, v: f64) -> [f64; 3] {
+        self.point_at(u, v).unwrap_or([f64::NAN; 3])
+    }
+
+    // Unit normal Su x Sv at (u, v), stepping towards the middle of the domain at degenerate points
+    fn normal(&self, u: f64, v: f64) -> Option<[f64; 3]> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges().ok()?;
+
+        let candidates = [
+            (u, v),
+            (
+                u + (0.5 * (u0 + u1) - u) * NORMAL_OFFSET,
+                v + (0.5 * (v0 + v1) - v) * NORMAL_OFFSET,
+            ),
+        ];
+        candidates.iter().find_map(|&(u, v)| {
+            let ders = self.rational_derivatives(u, v, 1).ok()?;
+            let (su, sv) = (ders[1][0], ders[0][1]);
+            let n = [
+                su[1] * sv[2] - su[2] * sv[1],
+                su[2] * sv[0] - su[0] * sv[2],
+                su[0] * sv[1] - su[1] * sv[0],
+            ];
+            let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+            if length < MIN_NORMAL_LENGTH {
+                return None;
+            }
+            Some(n.map(|c| c / length))
+        })
+    }
+
+    // Build the mesh of the grid spanned by the given parameter values
+    // Degenerate points get a zero normal, points that cannot be evaluated fail the mesh
+    fn build_mesh(&self, us: &[f64], vs: &[f64]) -> Result<SurfaceMesh, NurbsError> {
+        let ((u0, u1), (v0, v1)) = self.parameter_ranges()?;
+
+        // Vertex indices are u32 for WebGL, the callers keep the grid far below that
+        let too_large = || NurbsError::InvalidArgument("the mesh has too many vertices");
+        let rows = u32::try_from(us.len()).map_err(|_| too_large())?;
+        let columns = u32::try_from(vs.len()).map_err(|_| too_large())?;
+        rows.checked_mul(columns).ok_or_else(too_large)?;
+
+        let vertex_count = us.len() * vs.len();
+        let mut mesh = SurfaceMesh {
+            positions: Vec::with_capacity(vertex_count * 3),
+            normals: Vec::with_capacity(vertex_count * 3),
+            uvs: Vec::with_capacity(vertex_count * 2),
+            indices: Vec::with_capacity((us.len() - 1) * (vs.len() - 1) * 6),
+        };
+
+        for &u in us {
+            for &v in vs {
+                let position = self.point_at(u, v)?;
+                let normal = self.normal(u, v).unwrap_or([0.0; 3]);
+                mesh.positions.extend(position.iter().map(|&c| c as f32));
+                mesh.normals.extend(normal.iter().map(|&c| c as f32));
+                mesh.uvs.push(normalized(u, u0, u1) as f32);
+                mesh.uvs.push(normalized(v, v0, v1) as f32);
+            }
+        }
+
+        // Two triangles per cell, wound so their face normal follows Su x Sv
+        for i in 0..rows - 1 {
+            for j in 0..columns - 1 {
+                let a = i * columns + j;
+                let b = a + columns;
+                mesh.indices
+                    .extend_from_slice(&[a, b, b + 1, a, b + 1, a + 1]);
+            }
+        }
+
+        Ok(mesh)
+    }
+}
+
+// Evenly spaced parameters with exact end points
+fn uniform_parameters(start: f64, end: f64, segments: usize) -> Vec<f64> {
+    (0..=segments)
+        .map(|i| {
+            if i == segments {
+                end
+            } else {
+                start + (end - start) * i as f64 / segments as f64
+            }
+        })
+        .collect()
+}
+
+// Distinct knot values inside [start, end], including both ends
+fn distinct_knots(knots: &[f64], start: f64, end: f64) -> Vec<f64> {
+    let mut values = vec![start];
+    for &knot in knots {
+        if knot > *values.last().unwrap() && knot < end {
+            values.push(knot);
+        }
+    }
+    values.push(end);
+    values
+}
+
+// Insert the midpoint of every interval marked for splitting
+fn bisect(values: &[f64], split: &[bool]) -> Vec<f64> {
+    let mut refined = Vec::with_capacity(values.len() * 2);
+    for (pair, &split) in values.windows(2).zip(split) {
+        refined.push(pair[0]);
+        if split {
+            refined.push(0.5 * (pair[0] + pair[1]));
+        }
+    }
+    refined.push(values[values.len() - 1]);
+    refined
+}
+
+// Distance from a surface point to the average of the given chord or cell corners
+fn deviation(point: [f64; 3], corners: &[[f64; 3]]) -> f64 {
+    let scale = 1.0 / corners.len() as f64;
+    let mut squared = 0.0;
+    for (c, value) in point.iter().enumerate() {
+        let average: f64 = corners.iter().map(|corner| corner[c]).sum::<f64>() * scale;
+        squared += (value - average).powi(2);
+    }
+    squared.sqrt()
+}
+
+// Map a parameter from [start, end] to [0, 1]
+fn normalized(t: f64, start: f64, end: f64) -> f64 {
+    if end > start {
+        (t - start) / (end - start)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ControlPoint3, NurbsError, NurbsSurface};
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    // A quarter cylinder of radius 2 around the z axis, circular in u and straight in v
+    fn quarter_cylinder() -> NurbsSurface {
+        let mut surface = NurbsSurface::new(2, 1, 3, 2);
+        let arc = [(2.0, 0.0, 1.0), (2.0, 2.0, FRAC_1_SQRT_2), (0.0, 2.0, 1.0)];
+        for (i, &(x, y, w)) in arc.iter().enumerate() {
+            for (j, z) in [0.0, 3.0].iter().enumerate() {
+                surface
+                    .set_control_point(i, j, ControlPoint3::new(x, y, *z, w))
+                    .unwrap();
+            }
+        }
+        surface
+    }
+
+    // Largest distance of any triangle centroid from the cylinder
+    fn max_chordal_error(positions: &[f32], indices: &[u32]) -> f64 {
+        indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                let mut centroid = [0.0; 3];
+                for &index in triangle {
+                    for (c, value) in centroid.iter_mut().enumerate() {
+                        *value += positions[index as usize * 3 + c] as f64 / 3.0;
+                    }
+                }
+                (2.0 - centroid[0].hypot(centroid[1])).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_uniform_tessellation() {
+        let surface = quarter_cylinder();
+        let mesh = surface.tessellate_uniform(8, 2).unwrap();
+
+        assert_eq!(mesh.vertex_count(), 9 * 3);
+        assert_eq!(mesh.index_count(), 8 * 2 * 6);
+        assert_eq!(mesh.normals().len(), mesh.positions().len());
+        assert_eq!(mesh.uvs().len(), mesh.vertex_count() * 2);
+        assert!(mesh
+            .indices()
+            .iter()
+            .all(|&i| (i as usize) < mesh.vertex_count()));
+
+        // Normals point radially outwards and uvs cover the unit square
+        let positions = mesh.positions();
+        let normals = mesh.normals();
+        for k in 0..mesh.vertex_count() {
+            let (x, y) = (positions[3 * k] as f64, positions[3 * k + 1] as f64);
+            let (nx, ny, nz) = (normals[3 * k], normals[3 * k + 1], normals[3 * k + 2]);
+            assert!((nx as f64 - x / 2.0).abs() < 1e-6);
+            assert!((ny as f64 - y / 2.0).abs() < 1e-6);
+            assert!(nz.abs() < 1e-6);
+        }
+        let uvs = mesh.uvs();
+        assert_eq!(&uvs[..2], &[0.0, 0.0]);
+        assert_eq!(&uvs[uvs.len() - 2..], &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_triangles_face_along_normals() {
+        let surface = quarter_cylinder();
+        let mesh = surface.tessellate_uniform(4, 1).unwrap();
+        let p = mesh.positions();
+        let n = mesh.normals();
+
+        for triangle in mesh.indices().chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| triangle[k] as usize * 3);
+            let e1 = [p[b] - p[a], p[b + 1] - p[a + 1], p[b + 2] - p[a + 2]];
+            let e2 = [p[c] - p[a], p[c + 1] - p[a + 1], p[c + 2] - p[a + 2]];
+            let face = [
+                e1[1] * e2[2] - e1[2] * e2[1],
+                e1[2] * e2[0] - e1[0] * e2[2],
+                e1[0] * e2[1] - e1[1] * e2[0],
+            ];
+            assert!(face[0] * n[a] + face[1] * n[a + 1] + face[2] * n[a + 2] > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_tessellation_meets_tolerance() {
+        let surface = quarter_cylinder();
+
+        let coarse = surface.tessellate_adaptive(1e-2, 10).unwrap();
+        let fine = surface.tessellate_adaptive(1e-4, 10).unwrap();
+        assert!(fine.vertex_count() > coarse.vertex_count());
+
+        for (mesh, tolerance) in [(&coarse, 1e-2), (&fine, 1e-4)] {
+            assert!(max_chordal_error(&mesh.positions(), &mesh.indices()) <= tolerance);
+        }
+
+        // The straight direction needs no refinement
+        let rows = fine.vertex_count() / 2;
+        assert_eq!(fine.index_count(), (rows - 1) * 6);
+    }
+
+    #[test]
+    fn test_adaptive_tessellation_of_flat_patch() {
+        let mut surface = NurbsSurface::new(1, 1, 2, 2);
+        surface
+            .set_control_points(vec![
+                0.0, 0.0, 0.0, 1.0, //
+                0.0, 1.0, 0.0, 1.0, //
+                1.0, 0.0, 0.0, 1.0, //
+                1.0, 1.0, 0.0, 1.0,
+            ])
+            .unwrap();
+
+        let mesh = surface.tessellate_adaptive(1e-6, 10).unwrap();
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.indices().to_vec(), vec![0, 2, 3, 0, 3, 1]);
+    }
+
+    #[test]
+    fn test_adaptive_tessellation_limits() {
+        let surface = quarter_cylinder();
+        for tolerance in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                surface.tessellate_adaptive(tolerance, 4),
+                Err(NurbsError::InvalidArgument(_))
+            ));
+        }
+
+        // Only u needs refinement, 2 x 33 grid points fit and the next bisection to 2 x 65 does not
+        let (us, vs) = surface.adaptive_grid(1e-12, 64, 100).unwrap();
+        assert_eq!((us.len(), vs.len()), (33, 2));
+    }
+
+    #[test]
+    fn test_tessellate_invalid_surface() {
+        let surface = NurbsSurface::new(3, 3, 2, 0);
+        assert!(surface.tessellate_uniform(4, 4).is_err());
+        assert!(surface.tessellate_adaptive(0.1, 4).is_err());
+
+        // Grids beyond the vertex limit are refused before anything is allocated
+        let surface = quarter_cylinder();
+        for (segments_u, segments_v) in [(100_000, 100_000), (usize::MAX, 1), (1023, 1024)] {
+            assert!(matches!(
+                surface.tessellate_uniform(segments_u, segments_v),
+                Err(NurbsError::InvalidArgument(_))
+            ));
+        }
+    }
+}
@@ -493,11 +493,11 @@ declare module 'nurbs_wasm' {
     constructor(degree: number);
     add_control_point(control_point: ControlPoint): void;
     set_knots(knots: Float64Array): void;
-    evaluate(u: number): ControlPoint | null;
-    generate_points(num_points: number): (ControlPoint | null)[];
+    evaluate(u: number): ControlPoint;
+    generate_points(num_points: number): ControlPoint[];
     num_control_points(): number;
-    get_control_point(index: number): ControlPoint | null;
-    update_control_point(index: number, x: number, y: number, weight: number): boolean;
+    get_control_point(index: number): ControlPoint;
+    update_control_point(index: number, x: number, y: number, weight: number): void;
     get_degree(): number;
   }
 
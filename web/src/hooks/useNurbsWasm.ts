@@ -96,39 +96,28 @@ export function useNurbsWasm(): UseNurbsWasmReturn {
 			const newCurve = new NurbsCurve(curveDegree);
 			console.log("New curve created successfully");
 
-			// Only try to add control points if we have any
-			if (controlPoints.length > 0) {
-				console.log(`Adding ${controlPoints.length} control points to curve`);
-
-				// Add each control point individually with error handling
-				for (let i = 0; i < controlPoints.length; i++) {
-					try {
-						const point = controlPoints[i];
-
-						// Skip null or invalid points
-						if (
-							!point ||
-							typeof point.x !== "number" ||
-							typeof point.y !== "number" ||
-							typeof point.weight !== "number"
-						) {
-							console.log(`Skipping invalid control point at index ${i}`);
-							continue;
-						}
-
-						console.log(
-							`Adding control point ${i}: (${point.x}, ${point.y}, ${point.weight})`,
-						);
-
-						// Create a new point to ensure it's valid
-						const newPoint = new ControlPoint(point.x, point.y, point.weight);
-						newCurve.add_control_point(newPoint);
-					} catch (error) {
-						console.error(`Error adding control point at index ${i}:`, error);
-					}
-				}
+			// Skip null or invalid points
+			const validPoints = controlPoints.filter(
+				(point) =>
+					point &&
+					typeof point.x === "number" &&
+					typeof point.y === "number" &&
+					typeof point.weight === "number",
+			);
+			if (validPoints.length < controlPoints.length) {
+				console.log(
+					`Skipping ${controlPoints.length - validPoints.length} invalid control points`,
+				);
 			}
 
+			// Hand all control points over at once, the knots are generated a single time
+			newCurve.set_control_points_flat(
+				Float64Array.from(validPoints, (point) => point.x),
+				Float64Array.from(validPoints, (point) => point.y),
+				Float64Array.from(validPoints, (point) => point.weight),
+			);
+			console.log(`Added ${validPoints.length} control points to curve`);
+
 			// Update the curve state
 			setCurve(newCurve);
 			console.log("Curve updated successfully");
@@ -1,4 +1,22 @@
 declare module "nurbs_wasm" {
+	export type NurbsErrorKind =
+		| "EmptyCurve"
+		| "InvalidDegree"
+		| "InvalidKnots"
+		| "ZeroWeight"
+		| "SurfaceZeroWeight"
+		| "UndefinedTangent"
+		| "ParameterOutOfDomain"
+		| "IndexOutOfRange"
+		| "InvalidArgument"
+		| "ToleranceExceeded"
+		| "SingularSystem";
+
+	export interface NurbsError extends Error {
+		name: "NurbsError";
+		kind: NurbsErrorKind;
+	}
+
 	export class ControlPoint {
 		constructor(x: number, y: number, weight: number);
 		readonly x: number;
@@ -9,21 +27,221 @@ declare module "nurbs_wasm" {
 		set_weight(weight: number): void;
 	}
 
+	export enum Parameterization {
+		Uniform = 0,
+		ChordLength = 1,
+		Centripetal = 2,
+	}
+
+	export enum KnotStrategy {
+		UniformClamped = 0,
+		UniformUnclamped = 1,
+		ChordLength = 2,
+		Centripetal = 3,
+		Locked = 4,
+	}
+
+	export class CurveFit {
+		readonly curve: NurbsCurve;
+		readonly max_error: number;
+	}
+
+	export class CurvatureSamples {
+		readonly parameters: Float64Array;
+		readonly points: Float64Array;
+		readonly normals: Float64Array;
+		readonly curvatures: Float64Array;
+		readonly degenerate: Uint32Array;
+		len(): number;
+		is_empty(): boolean;
+	}
+
+	export class ClosestPoint {
+		readonly u: number;
+		readonly x: number;
+		readonly y: number;
+		readonly distance: number;
+	}
+
 	export class NurbsCurve {
 		constructor(degree: number);
 		add_control_point(control_point: ControlPoint): void;
 		set_knots(knots: Float64Array): void;
-		evaluate(u: number): ControlPoint | null;
-		generate_points(num_points: number): (ControlPoint | null)[];
+		evaluate(u: number): ControlPoint;
+		evaluate_many(params: Float64Array): Float64Array;
+		derivatives(u: number, order: number): Float64Array;
+		tangent_at(u: number): Float64Array;
+		normal_at(u: number): Float64Array;
+		curvature_at(u: number): number;
+		radius_of_curvature_at(u: number): number;
+		sample_curvature(num_samples: number): CurvatureSamples;
+		arc_length(u0: number, u1: number): number;
+		length(): number;
+		param_at_length(s: number): number;
+		generate_points_equidistant(num_points: number): ControlPoint[];
+		closest_point(x: number, y: number): ClosestPoint;
+		generate_points(num_points: number): ControlPoint[];
 		num_control_points(): number;
-		get_control_point(index: number): ControlPoint | null;
+		get_control_point(index: number): ControlPoint;
 		update_control_point(
 			index: number,
 			x: number,
 			y: number,
 			weight: number,
-		): boolean;
+		): void;
+		get_degree(): number;
+		effective_degree(): number;
+		set_degree(degree: number): void;
+		get_knots(): Float64Array;
+		domain(): Float64Array;
+		insert_control_point(index: number, control_point: ControlPoint): void;
+		remove_control_point(index: number): ControlPoint;
+		move_control_points(from: number, to: number): void;
+		set_control_points_flat(
+			xs: Float64Array,
+			ys: Float64Array,
+			ws: Float64Array,
+		): void;
+		clear(): void;
+		reverse(): void;
+		reparameterize(new_start: number, new_end: number): void;
+		insert_knot(u: number, times: number): number;
+		refine_knots(new_knots: Float64Array): number;
+		decompose_to_bezier(): NurbsCurve[];
+		remove_knot(u: number, times: number, tolerance: number): number;
+		simplify(tolerance: number): number;
+		elevate_degree(t: number): void;
+		reduce_degree(tolerance: number): void;
+		split(u: number): NurbsCurve[];
+		subcurve(u0: number, u1: number): NurbsCurve;
+		set_periodic(periodic: boolean): void;
+		is_periodic(): boolean;
+		is_closed(): boolean;
+		close(): void;
+		set_knot_strategy(strategy: KnotStrategy): void;
+		get_knot_strategy(): KnotStrategy;
+		static interpolate(
+			points: Float64Array,
+			degree: number,
+			parameterization: Parameterization,
+		): NurbsCurve;
+		static interpolate_with_end_tangents(
+			points: Float64Array,
+			degree: number,
+			parameterization: Parameterization,
+			start_tangent: Float64Array,
+			end_tangent: Float64Array,
+		): NurbsCurve;
+		static approximate(
+			points: Float64Array,
+			degree: number,
+			num_control_points: number,
+		): NurbsCurve;
+		static fit_to_tolerance(
+			points: Float64Array,
+			degree: number,
+			tolerance: number,
+		): CurveFit;
+		static circle(cx: number, cy: number, r: number): NurbsCurve;
+		static arc(
+			cx: number,
+			cy: number,
+			r: number,
+			start_angle: number,
+			end_angle: number,
+		): NurbsCurve;
+		static ellipse(
+			cx: number,
+			cy: number,
+			rx: number,
+			ry: number,
+			rotation: number,
+		): NurbsCurve;
+		static conic_from_three_points_and_tangents(
+			x0: number,
+			y0: number,
+			tx0: number,
+			ty0: number,
+			x2: number,
+			y2: number,
+			tx2: number,
+			ty2: number,
+			px: number,
+			py: number,
+		): NurbsCurve;
+	}
+
+	export class ControlPoint3 {
+		constructor(x: number, y: number, z: number, weight: number);
+		readonly x: number;
+		readonly y: number;
+		readonly z: number;
+		readonly weight: number;
+		set_x(x: number): void;
+		set_y(y: number): void;
+		set_z(z: number): void;
+		set_weight(weight: number): void;
+	}
+
+	export class NurbsCurve3 {
+		constructor(degree: number);
+		add_control_point(control_point: ControlPoint3): void;
+		set_knots(knots: Float64Array): void;
+		get_knots(): Float64Array;
+		domain(): Float64Array;
+		evaluate(u: number): Float64Array;
+		derivatives(u: number, order: number): Float64Array;
+		generate_points(num_points: number): Float64Array;
+		num_control_points(): number;
+		get_control_point(index: number): ControlPoint3;
+		update_control_point(index: number, x: number, y: number, z: number, weight: number): void;
 		get_degree(): number;
+		effective_degree(): number;
+		set_degree(degree: number): void;
+	}
+
+	export class NurbsSurface {
+		constructor(degree_u: number, degree_v: number, num_u: number, num_v: number);
+		num_u(): number;
+		num_v(): number;
+		get_degree_u(): number;
+		get_degree_v(): number;
+		effective_degree_u(): number;
+		effective_degree_v(): number;
+		get_control_point(i: number, j: number): ControlPoint3;
+		set_control_point(i: number, j: number, control_point: ControlPoint3): void;
+		set_control_points(points: Float64Array): void;
+		set_knots_u(knots: Float64Array): void;
+		set_knots_v(knots: Float64Array): void;
+		get_knots_u(): Float64Array;
+		get_knots_v(): Float64Array;
+		domain(): Float64Array;
+		evaluate(u: number, v: number): Float64Array;
+		derivatives(u: number, v: number, order: number): Float64Array;
+		tessellate_uniform(segments_u: number, segments_v: number): SurfaceMesh;
+		tessellate_adaptive(tolerance: number, max_depth: number): SurfaceMesh;
+	}
+
+	export class CurveEvaluator {
+		constructor(degree: number);
+		evaluate_into(curve: NurbsCurve, u: number, out: Float64Array): void;
+		evaluate_many_into(curve: NurbsCurve, params: Float64Array, out: Float64Array): void;
+		generate_points(curve: NurbsCurve, num_points: number): void;
+		points_ptr(): number;
+		point_count(): number;
+	}
+
+	export class SurfaceMesh {
+		readonly positions: Float32Array;
+		readonly normals: Float32Array;
+		readonly uvs: Float32Array;
+		readonly indices: Uint32Array;
+		positions_ptr(): number;
+		normals_ptr(): number;
+		uvs_ptr(): number;
+		indices_ptr(): number;
+		vertex_count(): number;
+		index_count(): number;
 	}
 
 	export function generate_nurbs_curve_points(
@@ -67,8 +67,7 @@ describe("NURBS WASM Module", () => {
 			curve.add_control_point(new ControlPoint(10, 20, 1));
 
 			// Update the point
-			const success = curve.update_control_point(0, 15, 25, 2);
-			expect(success).toBe(true);
+			curve.update_control_point(0, 15, 25, 2);
 
 			// Verify the update
 			const point = curve.get_control_point(0);
@@ -80,9 +79,10 @@ describe("NURBS WASM Module", () => {
 			}
 		});
 
-		it("returns false when updating non-existent control point", () => {
-			const success = curve.update_control_point(0, 15, 25, 2);
-			expect(success).toBe(false);
+		it("throws when updating non-existent control point", () => {
+			expect(() => curve.update_control_point(0, 15, 25, 2)).toThrow(
+				expect.objectContaining({ name: "NurbsError", kind: "IndexOutOfRange" }),
+			);
 		});
 
 		it("generates points along the curve", () => {
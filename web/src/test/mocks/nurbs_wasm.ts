@@ -1,4 +1,11 @@
 // Mock implementation of the WASM module for testing
+function nurbsError(kind: string, message: string) {
+	const error = new Error(message) as Error & { kind: string };
+	error.name = "NurbsError";
+	error.kind = kind;
+	return error;
+}
+
 export class ControlPoint {
 	x: number;
 	y: number;
@@ -40,20 +47,40 @@ export class NurbsCurve {
 		return this.degree;
 	}
 
+	set_degree(degree: number) {
+		this.degree = degree;
+	}
+
 	num_control_points() {
 		return this.controlPoints.length;
 	}
 
 	get_control_point(index: number) {
-		return this.controlPoints[index] || null;
+		if (index >= this.controlPoints.length) {
+			throw nurbsError("IndexOutOfRange", `index ${index} is out of range`);
+		}
+		return this.controlPoints[index];
 	}
 
 	update_control_point(index: number, x: number, y: number, weight: number) {
-		if (index < this.controlPoints.length) {
-			this.controlPoints[index] = { x, y, weight };
-			return true;
+		if (index >= this.controlPoints.length) {
+			throw nurbsError("IndexOutOfRange", `index ${index} is out of range`);
 		}
-		return false;
+		this.controlPoints[index] = { x, y, weight };
+	}
+
+	set_control_points_flat(xs: Float64Array, ys: Float64Array, ws: Float64Array) {
+		if (xs.length !== ys.length || xs.length !== ws.length) {
+			throw nurbsError(
+				"InvalidArgument",
+				"xs, ys and ws must have the same length",
+			);
+		}
+		this.controlPoints = Array.from(xs, (x, i) => ({
+			x,
+			y: ys[i],
+			weight: ws[i],
+		}));
 	}
 
 	generate_points(numPoints: number) {
@@ -89,11 +116,22 @@ export class NurbsCurve {
 	evaluate(u: number) {
 		// Simple mock implementation
 		if (this.controlPoints.length === 0) {
-			return null;
+			throw nurbsError("EmptyCurve", "there are no control points");
 		}
 		return this.controlPoints[0];
 	}
 
+	evaluate_many(params: Float64Array) {
+		// Simple mock implementation, every parameter maps to the first point as in evaluate
+		const out = new Float64Array(params.length * 2);
+		params.forEach((u, i) => {
+			const point = this.evaluate(u);
+			out[2 * i] = point.x;
+			out[2 * i + 1] = point.y;
+		});
+		return out;
+	}
+
 	set_knots(knots: Float64Array) {
 		// Mock implementation
 	}